@@ -199,6 +199,47 @@ fn bench_roundtrip(c: &mut Criterion) {
     group.finish();
 }
 
+#[cfg(target_arch = "x86_64")]
+fn bench_bitpack_simd(c: &mut Criterion) {
+    use bitpack::simd;
+
+    let mut group = c.benchmark_group("bitpack_simd");
+
+    for size in [800, 8_000, 80_000].iter() {
+        let values: Vec<i32> = (0..*size).map(|i| (i % 16) as i32).collect();
+        let i64_values: Vec<i64> = values.iter().map(|&v| v as i64).collect();
+
+        group.bench_with_input(BenchmarkId::new("scalar", size), size, |b, _| {
+            b.iter(|| {
+                let packed = bitpack::pack(black_box(&i64_values), 4).unwrap();
+                black_box(packed);
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("avx2", size), size, |b, _| {
+            b.iter(|| {
+                let packed = simd::pack_i32(black_box(&values), 4).unwrap();
+                black_box(packed);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(target_arch = "x86_64")]
+criterion_group!(
+    benches,
+    bench_rle_encoding,
+    bench_delta_encoding,
+    bench_dictionary_encoding,
+    bench_bitpack_encoding,
+    bench_bitpack_simd,
+    bench_compression_ratio,
+    bench_roundtrip
+);
+
+#[cfg(not(target_arch = "x86_64"))]
 criterion_group!(
     benches,
     bench_rle_encoding,
@@ -208,4 +249,5 @@ criterion_group!(
     bench_compression_ratio,
     bench_roundtrip
 );
+
 criterion_main!(benches);
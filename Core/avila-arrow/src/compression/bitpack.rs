@@ -139,9 +139,56 @@ pub fn detect_bit_width(data: &[i64]) -> u8 {
     64 - max_value.leading_zeros() as u8
 }
 
+/// Zigzag-encode a signed integer into an unsigned one with small absolute
+/// magnitude mapped to small unsigned values: `0, -1, 1, -2, 2, ...` becomes
+/// `0, 1, 2, 3, 4, ...`. Lets negative values round-trip through `pack`/`unpack`
+/// without needing the full 64-bit width that raw two's-complement would require.
+#[inline]
+pub fn zigzag_encode(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+/// Invert [`zigzag_encode`]
+#[inline]
+pub fn zigzag_decode(z: u64) -> i64 {
+    ((z >> 1) as i64) ^ -((z & 1) as i64)
+}
+
+/// Required bit width for zigzag-encoded data (see [`zigzag_encode`])
+pub fn detect_bit_width_zigzag(data: &[i64]) -> u8 {
+    if data.is_empty() {
+        return 0;
+    }
+
+    let max_value = data.iter().map(|&v| zigzag_encode(v)).max().unwrap_or(0);
+
+    if max_value == 0 {
+        return 1;
+    }
+
+    64 - max_value.leading_zeros() as u8
+}
+
+/// Pack signed values via zigzag so negatives don't need the full bit width
+pub fn pack_zigzag(data: &[i64], bit_width: u8) -> Result<Vec<u8>> {
+    let zigzagged: Vec<i64> = data.iter().map(|&v| zigzag_encode(v) as i64).collect();
+    pack(&zigzagged, bit_width)
+}
+
+/// Unpack values packed by [`pack_zigzag`]
+pub fn unpack_zigzag(data: &[u8], bit_width: u8, count: usize) -> Result<Vec<i64>> {
+    let raw = unpack(data, bit_width, count)?;
+    Ok(raw.into_iter().map(|v| zigzag_decode(v as u64)).collect())
+}
+
 /// Bit-packing encoder with auto bit-width detection
+///
+/// Falls back to zigzag encoding as soon as a negative value is seen, so the
+/// packed bit width reflects the magnitude of the values rather than blowing
+/// up to 64 bits for raw two's-complement negatives.
 pub struct BitPackEncoder {
     values: Vec<i64>,
+    zigzag: bool,
 }
 
 impl BitPackEncoder {
@@ -149,20 +196,62 @@ impl BitPackEncoder {
     pub fn new() -> Self {
         Self {
             values: Vec::new(),
+            zigzag: false,
         }
     }
 
     /// Add value to encode
     pub fn encode(&mut self, value: i64) {
+        if value < 0 {
+            self.zigzag = true;
+        }
         self.values.push(value);
     }
 
     /// Finish encoding
-    pub fn finish(self) -> Result<(Vec<u8>, u8, usize)> {
-        let bit_width = detect_bit_width(&self.values);
+    ///
+    /// Returns `(packed_bytes, bit_width, count, zigzag)`; `zigzag` must be
+    /// passed back to the matching unpack path (`unpack_zigzag` vs `unpack`).
+    pub fn finish(self) -> Result<(Vec<u8>, u8, usize, bool)> {
         let count = self.values.len();
-        let packed = pack(&self.values, bit_width)?;
-        Ok((packed, bit_width, count))
+
+        if self.zigzag {
+            let bit_width = detect_bit_width_zigzag(&self.values);
+            let packed = pack_zigzag(&self.values, bit_width)?;
+            Ok((packed, bit_width, count, true))
+        } else {
+            let bit_width = detect_bit_width(&self.values);
+            let packed = pack(&self.values, bit_width)?;
+            Ok((packed, bit_width, count, false))
+        }
+    }
+
+    /// Finish encoding, choosing whichever of fixed-width bit-packing or
+    /// [`VarIntEncoder`]-style varint encoding produces fewer bytes for the
+    /// collected values. Good for high-variance columns where most values are
+    /// tiny but a few outliers would otherwise force a wide fixed bit width.
+    pub fn finish_adaptive(self) -> Result<(Vec<u8>, usize, AdaptiveCodec)> {
+        let count = self.values.len();
+
+        let (bitpack_bytes, bit_width, zigzag) = if self.zigzag {
+            let bit_width = detect_bit_width_zigzag(&self.values);
+            (pack_zigzag(&self.values, bit_width)?, bit_width, true)
+        } else {
+            let bit_width = detect_bit_width(&self.values);
+            (pack(&self.values, bit_width)?, bit_width, false)
+        };
+
+        let varint_bytes = varint_pack(&self.values);
+
+        if varint_bytes.len() < bitpack_bytes.len() {
+            Ok((varint_bytes, count, AdaptiveCodec::VarInt))
+        } else {
+            Ok((
+                bitpack_bytes,
+                count,
+                AdaptiveCodec::BitPack { bit_width, zigzag },
+            ))
+        }
     }
 }
 
@@ -172,6 +261,218 @@ impl Default for BitPackEncoder {
     }
 }
 
+/// Codec picked by [`BitPackEncoder::finish_adaptive`]; tells the reader which
+/// unpack path to use for the returned bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdaptiveCodec {
+    BitPack { bit_width: u8, zigzag: bool },
+    VarInt,
+}
+
+/// Bitcoin-style prefixed variable-length integer: values `< 0xFD` encode in a
+/// single byte, `0xFD`/`0xFE`/`0xFF` are markers for a trailing `u16`/`u32`/`u64`
+/// (little-endian). Small values cost one byte regardless of the column's peak.
+pub fn write_varint(output: &mut Vec<u8>, value: u64) {
+    if value < 0xFD {
+        output.push(value as u8);
+    } else if value <= u16::MAX as u64 {
+        output.push(0xFD);
+        output.extend_from_slice(&(value as u16).to_le_bytes());
+    } else if value <= u32::MAX as u64 {
+        output.push(0xFE);
+        output.extend_from_slice(&(value as u32).to_le_bytes());
+    } else {
+        output.push(0xFF);
+        output.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Decode a single varint from the front of `data`; returns `(value, bytes_consumed)`
+pub fn read_varint(data: &[u8]) -> Result<(u64, usize)> {
+    match data.first() {
+        None => Err(ArrowError::InvalidData("Varint data empty".to_string())),
+        Some(0xFD) => {
+            if data.len() < 3 {
+                return Err(ArrowError::InvalidData("Truncated u16 varint".to_string()));
+            }
+            Ok((u16::from_le_bytes([data[1], data[2]]) as u64, 3))
+        }
+        Some(0xFE) => {
+            if data.len() < 5 {
+                return Err(ArrowError::InvalidData("Truncated u32 varint".to_string()));
+            }
+            Ok((u32::from_le_bytes(data[1..5].try_into().unwrap()) as u64, 5))
+        }
+        Some(0xFF) => {
+            if data.len() < 9 {
+                return Err(ArrowError::InvalidData("Truncated u64 varint".to_string()));
+            }
+            Ok((u64::from_le_bytes(data[1..9].try_into().unwrap()), 9))
+        }
+        Some(&marker) => Ok((marker as u64, 1)),
+    }
+}
+
+/// Pack signed integers as zigzag + varint; best for columns where most
+/// values are small but a few outliers would otherwise force a wide fixed bit width
+pub fn varint_pack(data: &[i64]) -> Vec<u8> {
+    let mut output = Vec::new();
+    for &value in data {
+        write_varint(&mut output, zigzag_encode(value));
+    }
+    output
+}
+
+/// Unpack values packed by [`varint_pack`]
+pub fn varint_unpack(data: &[u8], count: usize) -> Result<Vec<i64>> {
+    let mut output = Vec::with_capacity(count);
+    let mut pos = 0;
+
+    for _ in 0..count {
+        let (zigzagged, consumed) = read_varint(&data[pos..])?;
+        output.push(zigzag_decode(zigzagged));
+        pos += consumed;
+    }
+
+    Ok(output)
+}
+
+/// Varint encoder mirroring [`BitPackEncoder`] for high-variance columns
+pub struct VarIntEncoder {
+    values: Vec<i64>,
+}
+
+impl VarIntEncoder {
+    /// Create new encoder
+    pub fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Add value to encode
+    pub fn encode(&mut self, value: i64) {
+        self.values.push(value);
+    }
+
+    /// Finish encoding, returning the packed bytes and the value count
+    pub fn finish(self) -> (Vec<u8>, usize) {
+        let count = self.values.len();
+        (varint_pack(&self.values), count)
+    }
+}
+
+impl Default for VarIntEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hybrid of RLE and fixed-width bit-packing: long runs of an identical value
+/// collapse to a single packed value, everything else is bit-packed at a
+/// shared `bit_width`. Good for columns like dictionary indices where most
+/// values repeat in long stretches but a minority don't repeat at all.
+///
+/// Wire format is a sequence of varint-headed groups. The header's low bit
+/// selects the mode:
+/// - `0`: RLE run. The header holds `run_len << 1`, followed by one
+///   `bit_width`-sized packed value repeated `run_len` times.
+/// - `1`: literal group. The header holds `literal_count << 1 | 1`, followed
+///   by `literal_count` values bit-packed at `bit_width` (padded up to a
+///   multiple of 8 values before packing, trimmed back on decode).
+pub struct RleBitPackHybrid;
+
+impl RleBitPackHybrid {
+    /// Runs of at least this many equal values are emitted as RLE instead of
+    /// being bit-packed as literals.
+    const RUN_THRESHOLD: usize = 8;
+
+    /// Encode `data`, returning `(bytes, bit_width, count)`.
+    ///
+    /// Always zigzags values before bit-packing them (like [`varint_pack`],
+    /// unconditionally rather than only when a negative is seen), so a
+    /// negative run or literal doesn't get bit-packed via its raw
+    /// two's-complement magnitude and truncated on the way back out.
+    pub fn encode(data: &[i64]) -> Result<(Vec<u8>, u8, usize)> {
+        let count = data.len();
+        let bit_width = detect_bit_width_zigzag(data);
+        let mut output = Vec::new();
+        let mut literals: Vec<i64> = Vec::new();
+
+        let mut i = 0;
+        while i < data.len() {
+            let value = data[i];
+            let mut run_len = 1usize;
+            while i + run_len < data.len() && data[i + run_len] == value {
+                run_len += 1;
+            }
+
+            if run_len >= Self::RUN_THRESHOLD {
+                Self::flush_literals(&mut literals, bit_width, &mut output)?;
+
+                write_varint(&mut output, (run_len as u64) << 1);
+                output.extend_from_slice(&pack_zigzag(&[value], bit_width)?);
+            } else {
+                literals.extend(std::iter::repeat(value).take(run_len));
+            }
+
+            i += run_len;
+        }
+
+        Self::flush_literals(&mut literals, bit_width, &mut output)?;
+
+        Ok((output, bit_width, count))
+    }
+
+    /// Pack any buffered literals as one group, padding to a multiple of 8
+    /// values for `pack_zigzag` and recording the real count in the header.
+    fn flush_literals(literals: &mut Vec<i64>, bit_width: u8, output: &mut Vec<u8>) -> Result<()> {
+        if literals.is_empty() {
+            return Ok(());
+        }
+
+        let literal_count = literals.len();
+        let padded_len = (literal_count + 7) / 8 * 8;
+        literals.resize(padded_len, 0);
+
+        write_varint(output, ((literal_count as u64) << 1) | 1);
+        output.extend_from_slice(&pack_zigzag(literals, bit_width)?);
+
+        literals.clear();
+        Ok(())
+    }
+
+    /// Decode bytes produced by [`RleBitPackHybrid::encode`]
+    pub fn decode(data: &[u8], bit_width: u8, count: usize) -> Result<Vec<i64>> {
+        let value_bytes = (bit_width as usize + 7) / 8;
+        let mut output = Vec::with_capacity(count);
+        let mut pos = 0;
+
+        while output.len() < count {
+            let (header, consumed) = read_varint(&data[pos..])?;
+            pos += consumed;
+
+            if header & 1 == 0 {
+                let run_len = (header >> 1) as usize;
+                let packed = &data[pos..pos + value_bytes];
+                let value = unpack_zigzag(packed, bit_width, 1)?[0];
+                pos += value_bytes;
+
+                output.extend(std::iter::repeat(value).take(run_len));
+            } else {
+                let literal_count = (header >> 1) as usize;
+                let padded_len = (literal_count + 7) / 8 * 8;
+                let byte_len = (padded_len * bit_width as usize + 7) / 8;
+
+                let values = unpack_zigzag(&data[pos..pos + byte_len], bit_width, padded_len)?;
+                pos += byte_len;
+
+                output.extend_from_slice(&values[..literal_count]);
+            }
+        }
+
+        Ok(output)
+    }
+}
+
 /// SIMD-accelerated bit-packing for 32-bit values
 #[cfg(target_arch = "x86_64")]
 pub mod simd {
@@ -180,42 +481,285 @@ pub mod simd {
     #[cfg(target_arch = "x86_64")]
     use std::arch::x86_64::*;
 
-    /// Pack 8 x i32 values with SIMD (AVX2)
+    /// Pack 8 x i32 values at a time, emitting exactly `bit_width` bytes per
+    /// group of 8 — the same bit layout [`pack`] would produce for an
+    /// isolated 8-value slice. Falls back to the scalar path when AVX2 isn't
+    /// available at runtime; the values past the last full group of 8 are
+    /// always packed by the scalar path.
+    pub fn pack_i32(data: &[i32], bit_width: u8) -> Result<Vec<u8>> {
+        if bit_width == 0 || bit_width > 32 {
+            return Err(ArrowError::InvalidData(format!(
+                "Invalid bit width: {}",
+                bit_width
+            )));
+        }
+
+        let chunks = data.len() / 8;
+        let tail = &data[chunks * 8..];
+
+        let mut output = if is_x86_feature_detected!("avx2") {
+            let mut output = Vec::with_capacity(data.len() * bit_width as usize / 8 + 8);
+            for chunk in data[..chunks * 8].chunks_exact(8) {
+                output.extend_from_slice(unsafe { &pack_i32_x8(chunk, bit_width) });
+            }
+            output
+        } else {
+            pack_i32_scalar(&data[..chunks * 8], bit_width)
+        };
+
+        // Scalar tail: whatever doesn't fill a full group of 8
+        output.extend_from_slice(&pack_i32_scalar(tail, bit_width));
+        Ok(output)
+    }
+
+    /// Unpack values produced by [`pack_i32`]
+    pub fn unpack_i32(data: &[u8], bit_width: u8, count: usize) -> Result<Vec<i32>> {
+        if bit_width == 0 || bit_width > 32 {
+            return Err(ArrowError::InvalidData(format!(
+                "Invalid bit width: {}",
+                bit_width
+            )));
+        }
+
+        let chunks = count / 8;
+        let tail_count = count - chunks * 8;
+        let group_bytes = bit_width as usize;
+
+        let mut output = Vec::with_capacity(count);
+        if is_x86_feature_detected!("avx2") {
+            for g in 0..chunks {
+                let group = &data[g * group_bytes..(g + 1) * group_bytes];
+                output.extend_from_slice(&unsafe { unpack_i32_x8(group, bit_width) });
+            }
+        } else {
+            output.extend(unpack_i32_scalar(&data[..chunks * group_bytes], bit_width, chunks * 8));
+        }
+
+        if tail_count > 0 {
+            let tail_data = &data[chunks * group_bytes..];
+            output.extend(unpack_i32_scalar(tail_data, bit_width, tail_count));
+        }
+
+        Ok(output)
+    }
+
+    fn pack_i32_scalar(data: &[i32], bit_width: u8) -> Vec<u8> {
+        let values: Vec<i64> = data.iter().map(|&v| v as i64).collect();
+        pack(&values, bit_width).unwrap_or_default()
+    }
+
+    fn unpack_i32_scalar(data: &[u8], bit_width: u8, count: usize) -> Vec<i32> {
+        unpack(data, bit_width, count)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| v as i32)
+            .collect()
+    }
+
+    /// Pack exactly 8 x i32 values with AVX2, emitting exactly `bit_width`
+    /// bytes. Each lane is masked to `bit_width` bits then shifted left by
+    /// its destination bit offset (`_mm256_sllv_epi32`); a second pass
+    /// computes the bits that overflow past the 32-bit lane they start in
+    /// (`_mm256_srlv_epi32`). AVX2 has no generic cross-lane scatter, so
+    /// placing each lane's (possibly carried) bits into the right output
+    /// word is a short scalar merge over the 8 extracted lanes.
+    ///
+    /// # Safety
+    /// Caller must ensure AVX2 is available (e.g. via `is_x86_feature_detected!("avx2")`),
+    /// `data.len() == 8`, and `1 <= bit_width <= 32`.
     #[target_feature(enable = "avx2")]
     pub unsafe fn pack_i32_x8(data: &[i32], bit_width: u8) -> Vec<u8> {
-        if data.len() < 8 || bit_width > 32 {
-            return vec![];
+        let mask = if bit_width == 32 {
+            u32::MAX
+        } else {
+            (1u32 << bit_width) - 1
+        };
+
+        let bw = bit_width as u32;
+        let mut bit_off = [0i32; 8];
+        let mut word_lo = [0usize; 8];
+        let mut has_overflow = [0i32; 8];
+        let mut shift_hi = [0i32; 8];
+        for lane in 0..8 {
+            let start_bit = lane as u32 * bw;
+            bit_off[lane] = (start_bit % 32) as i32;
+            word_lo[lane] = (start_bit / 32) as usize;
+            has_overflow[lane] = if bit_off[lane] > 0 && bit_off[lane] as u32 + bw > 32 {
+                -1
+            } else {
+                0
+            };
+            shift_hi[lane] = if bit_off[lane] > 0 { 32 - bit_off[lane] } else { 0 };
+        }
+
+        let values = _mm256_loadu_si256(data.as_ptr() as *const __m256i);
+        let mask_vec = _mm256_set1_epi32(mask as i32);
+        let masked = _mm256_and_si256(values, mask_vec);
+
+        let lo_shift = _mm256_loadu_si256(bit_off.as_ptr() as *const __m256i);
+        let main_shifted = _mm256_sllv_epi32(masked, lo_shift);
+
+        let hi_shift = _mm256_loadu_si256(shift_hi.as_ptr() as *const __m256i);
+        let raw_overflow = _mm256_srlv_epi32(masked, hi_shift);
+        let overflow_mask = _mm256_loadu_si256(has_overflow.as_ptr() as *const __m256i);
+        let overflow = _mm256_and_si256(raw_overflow, overflow_mask);
+
+        let mut main_lanes = [0u32; 8];
+        let mut overflow_lanes = [0u32; 8];
+        _mm256_storeu_si256(main_lanes.as_mut_ptr() as *mut __m256i, main_shifted);
+        _mm256_storeu_si256(overflow_lanes.as_mut_ptr() as *mut __m256i, overflow);
+
+        let out_bytes = bit_width as usize;
+        let out_words = (out_bytes + 3) / 4;
+        let mut words = vec![0u32; out_words];
+        for lane in 0..8 {
+            words[word_lo[lane]] |= main_lanes[lane];
+            if has_overflow[lane] != 0 && word_lo[lane] + 1 < out_words {
+                words[word_lo[lane] + 1] |= overflow_lanes[lane];
+            }
+        }
+
+        let mut output = Vec::with_capacity(out_bytes);
+        for word in &words {
+            output.extend_from_slice(&word.to_le_bytes());
+        }
+        output.truncate(out_bytes);
+        output
+    }
+
+    /// Unpack exactly 8 x i32 values packed by [`pack_i32_x8`], mirroring it
+    /// with `_mm256_srlv_epi32` for the low bits of each lane and
+    /// `_mm256_sllv_epi32` to re-align any carried-over high bits.
+    ///
+    /// # Safety
+    /// Caller must ensure AVX2 is available, `data.len() == bit_width as usize`,
+    /// and `1 <= bit_width <= 32`.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn unpack_i32_x8(data: &[u8], bit_width: u8) -> [i32; 8] {
+        let bw = bit_width as u32;
+        let out_words = ((bit_width as usize) + 3) / 4;
+
+        let mut padded = [0u8; 32];
+        let n = data.len().min(32);
+        padded[..n].copy_from_slice(&data[..n]);
+
+        let mut words = [0u32; 8];
+        for (w, word) in words.iter_mut().enumerate().take(out_words) {
+            *word = u32::from_le_bytes([
+                padded[4 * w],
+                padded[4 * w + 1],
+                padded[4 * w + 2],
+                padded[4 * w + 3],
+            ]);
+        }
+
+        let mut lo_word = [0i32; 8];
+        let mut hi_word = [0i32; 8];
+        let mut bit_off = [0i32; 8];
+        let mut bits_from_lo = [0i32; 8];
+        let mut has_hi = [0i32; 8];
+        for lane in 0..8 {
+            let start_bit = lane as u32 * bw;
+            let word_lo = (start_bit / 32) as usize;
+            bit_off[lane] = (start_bit % 32) as i32;
+            lo_word[lane] = words[word_lo] as i32;
+            let from_lo = (32 - bit_off[lane]).min(bw as i32);
+            bits_from_lo[lane] = from_lo;
+            if from_lo < bw as i32 {
+                has_hi[lane] = -1;
+                hi_word[lane] = words[word_lo + 1] as i32;
+            }
         }
 
+        let lo_vec = _mm256_loadu_si256(lo_word.as_ptr() as *const __m256i);
+        let off_vec = _mm256_loadu_si256(bit_off.as_ptr() as *const __m256i);
+        let lo_shifted = _mm256_srlv_epi32(lo_vec, off_vec);
+
         let mask = if bit_width == 32 {
             u32::MAX
         } else {
             (1u32 << bit_width) - 1
         };
+        let mask_vec = _mm256_set1_epi32(mask as i32);
+        let lo_part = _mm256_and_si256(lo_shifted, mask_vec);
 
-        let mut output = Vec::new();
-        let mut i = 0;
+        let from_lo_vec = _mm256_loadu_si256(bits_from_lo.as_ptr() as *const __m256i);
+        let hi_vec = _mm256_loadu_si256(hi_word.as_ptr() as *const __m256i);
+        let hi_shifted = _mm256_sllv_epi32(hi_vec, from_lo_vec);
+        let has_hi_vec = _mm256_loadu_si256(has_hi.as_ptr() as *const __m256i);
+        let hi_part = _mm256_and_si256(hi_shifted, has_hi_vec);
 
-        while i + 8 <= data.len() {
-            // Load 8 values
-            let values = _mm256_loadu_si256(data[i..].as_ptr() as *const __m256i);
+        let combined = _mm256_or_si256(lo_part, hi_part);
+        let final_masked = _mm256_and_si256(combined, mask_vec);
 
-            // Apply mask
-            let mask_vec = _mm256_set1_epi32(mask as i32);
-            let masked = _mm256_and_si256(values, mask_vec);
+        let mut out = [0i32; 8];
+        _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, final_masked);
+        out
+    }
 
-            // Store packed values (simplified - full impl would pack bits)
-            let mut buffer = [0i32; 8];
-            _mm256_storeu_si256(buffer.as_mut_ptr() as *mut __m256i, masked);
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-            for &v in &buffer {
-                output.extend_from_slice(&v.to_le_bytes());
+        #[test]
+        fn test_pack_i32_x8_matches_scalar_pack_for_one_group() {
+            if !is_x86_feature_detected!("avx2") {
+                return;
             }
 
-            i += 8;
+            for bit_width in 1u8..=32 {
+                let max_val: i64 = if bit_width == 32 {
+                    u32::MAX as i64
+                } else {
+                    (1i64 << bit_width) - 1
+                };
+                let values: Vec<i64> = (0..8).map(|i| (i * 7) % (max_val + 1)).collect();
+                let i32_values: Vec<i32> = values.iter().map(|&v| v as i32).collect();
+
+                let scalar_bytes = pack(&values, bit_width).unwrap();
+                let simd_bytes = unsafe { pack_i32_x8(&i32_values, bit_width) };
+                assert_eq!(
+                    scalar_bytes, simd_bytes,
+                    "mismatch at bit_width={}",
+                    bit_width
+                );
+            }
         }
 
-        output
+        #[test]
+        fn test_pack_unpack_i32_x8_roundtrip() {
+            if !is_x86_feature_detected!("avx2") {
+                return;
+            }
+
+            for bit_width in 1u8..=32 {
+                let max_val: i64 = if bit_width == 32 {
+                    u32::MAX as i64
+                } else {
+                    (1i64 << bit_width) - 1
+                };
+                let values: Vec<i32> = (0..8)
+                    .map(|i| ((i as i64 * 11) % (max_val + 1)) as i32)
+                    .collect();
+
+                let packed = unsafe { pack_i32_x8(&values, bit_width) };
+                let unpacked = unsafe { unpack_i32_x8(&packed, bit_width) };
+                assert_eq!(unpacked.as_slice(), values.as_slice(), "bit_width={}", bit_width);
+            }
+        }
+
+        #[test]
+        fn test_pack_i32_roundtrip_with_scalar_tail() {
+            if !is_x86_feature_detected!("avx2") {
+                return;
+            }
+
+            // 19 values: two full groups of 8 plus a 3-value scalar tail
+            let values: Vec<i32> = (0..19).map(|i| i % 16).collect();
+            let packed = pack_i32(&values, 4).unwrap();
+            let unpacked = unpack_i32(&packed, 4, values.len()).unwrap();
+            assert_eq!(unpacked, values);
+        }
     }
 }
 
@@ -262,15 +806,115 @@ mod tests {
             encoder.encode(i % 16); // Values 0-15 need 4 bits
         }
 
-        let (packed, bit_width, count) = encoder.finish().unwrap();
+        let (packed, bit_width, count, zigzag) = encoder.finish().unwrap();
         assert_eq!(bit_width, 4);
         assert_eq!(count, 100);
+        assert!(!zigzag);
 
         let unpacked = unpack(&packed, bit_width, count).unwrap();
         let expected: Vec<i64> = (0..100).map(|i| i % 16).collect();
         assert_eq!(unpacked, expected);
     }
 
+    #[test]
+    fn test_zigzag_encode_decode_roundtrip() {
+        for n in [-1000i64, -1, 0, 1, 1000, i64::MIN, i64::MAX] {
+            assert_eq!(zigzag_decode(zigzag_encode(n)), n);
+        }
+    }
+
+    #[test]
+    fn test_zigzag_small_negatives_use_few_bits() {
+        // -1 should need just 1 bit once zigzag-encoded (maps to 1)
+        assert_eq!(detect_bit_width_zigzag(&[-1]), 1);
+        // -1 via raw bit-packing would otherwise need 64 bits
+        let data = vec![-1i64];
+        let bit_width = detect_bit_width_zigzag(&data);
+        let packed = pack_zigzag(&data, bit_width).unwrap();
+        let unpacked = unpack_zigzag(&packed, bit_width, data.len()).unwrap();
+        assert_eq!(unpacked, data);
+    }
+
+    #[test]
+    fn test_bitpack_encoder_with_negatives_roundtrips() {
+        let mut encoder = BitPackEncoder::new();
+        let values: Vec<i64> = (-50..50).collect();
+        for &v in &values {
+            encoder.encode(v);
+        }
+
+        let (packed, bit_width, count, zigzag) = encoder.finish().unwrap();
+        assert!(zigzag);
+
+        let unpacked = unpack_zigzag(&packed, bit_width, count).unwrap();
+        assert_eq!(unpacked, values);
+    }
+
+    #[test]
+    fn test_varint_roundtrip_mixed_magnitudes() {
+        let data: Vec<i64> = vec![0, 1, -1, 127, 300, -300, 70_000, i32::MAX as i64, i64::MAX];
+        let packed = varint_pack(&data);
+        let unpacked = varint_unpack(&packed, data.len()).unwrap();
+        assert_eq!(unpacked, data);
+    }
+
+    #[test]
+    fn test_varint_small_values_cost_one_byte() {
+        let data: Vec<i64> = (0..50).collect(); // zigzag(0..50) all < 0xFD
+        let packed = varint_pack(&data);
+        assert_eq!(packed.len(), data.len());
+    }
+
+    #[test]
+    fn test_varint_encoder() {
+        let mut encoder = VarIntEncoder::new();
+        for v in [1i64, -2, 300, -70_000] {
+            encoder.encode(v);
+        }
+        let (packed, count) = encoder.finish();
+
+        let unpacked = varint_unpack(&packed, count).unwrap();
+        assert_eq!(unpacked, vec![1, -2, 300, -70_000]);
+    }
+
+    #[test]
+    fn test_finish_adaptive_picks_varint_for_high_variance_column() {
+        // Mostly tiny values plus one huge outlier: fixed bit-packing must size
+        // every value to the outlier's width, varint should do much better.
+        let mut encoder = BitPackEncoder::new();
+        for _ in 0..100 {
+            encoder.encode(1);
+        }
+        encoder.encode(i64::MAX);
+
+        let (packed, count, codec) = encoder.finish_adaptive().unwrap();
+        assert_eq!(codec, AdaptiveCodec::VarInt);
+
+        let unpacked = varint_unpack(&packed, count).unwrap();
+        assert_eq!(unpacked.len(), 101);
+        assert_eq!(unpacked[100], i64::MAX);
+    }
+
+    #[test]
+    fn test_finish_adaptive_picks_bitpack_for_uniform_column() {
+        let mut encoder = BitPackEncoder::new();
+        for i in 0..200 {
+            encoder.encode(i % 16);
+        }
+
+        let (packed, count, codec) = encoder.finish_adaptive().unwrap();
+        match codec {
+            AdaptiveCodec::BitPack { bit_width, zigzag } => {
+                assert_eq!(bit_width, 4);
+                assert!(!zigzag);
+                let unpacked = unpack(&packed, bit_width, count).unwrap();
+                let expected: Vec<i64> = (0..200).map(|i| i % 16).collect();
+                assert_eq!(unpacked, expected);
+            }
+            AdaptiveCodec::VarInt => panic!("expected bit-packing to win for a uniform column"),
+        }
+    }
+
     #[test]
     fn test_bitpack_roundtrip() {
         for bit_width in 1..=16 {
@@ -282,4 +926,62 @@ mod tests {
             assert_eq!(unpacked, data, "Failed for bit_width={}", bit_width);
         }
     }
+
+    #[test]
+    fn test_rle_bitpack_hybrid_all_equal() {
+        let data = vec![42i64; 50];
+
+        let (packed, bit_width, count) = RleBitPackHybrid::encode(&data).unwrap();
+        let unpacked = RleBitPackHybrid::decode(&packed, bit_width, count).unwrap();
+
+        assert_eq!(unpacked, data);
+    }
+
+    #[test]
+    fn test_rle_bitpack_hybrid_all_distinct() {
+        let data: Vec<i64> = (0..50).collect();
+
+        let (packed, bit_width, count) = RleBitPackHybrid::encode(&data).unwrap();
+        let unpacked = RleBitPackHybrid::decode(&packed, bit_width, count).unwrap();
+
+        assert_eq!(unpacked, data);
+    }
+
+    #[test]
+    fn test_rle_bitpack_hybrid_mixed() {
+        let mut data = vec![7i64; 20]; // long run -> RLE
+        data.extend([1, 2, 3, 1, 2]); // short, non-repeating -> literals
+        data.extend(vec![9i64; 10]); // another run -> RLE
+        data.extend([4, 5, 6]); // trailing literals not a multiple of 8
+
+        let (packed, bit_width, count) = RleBitPackHybrid::encode(&data).unwrap();
+        let unpacked = RleBitPackHybrid::decode(&packed, bit_width, count).unwrap();
+
+        assert_eq!(unpacked, data);
+    }
+
+    #[test]
+    fn test_rle_bitpack_hybrid_short_run_stays_literal() {
+        // A run shorter than RUN_THRESHOLD should be packed as literals, not RLE
+        let data = vec![3i64, 3, 3, 5, 5, 5, 5, 5];
+
+        let (packed, bit_width, count) = RleBitPackHybrid::encode(&data).unwrap();
+        let unpacked = RleBitPackHybrid::decode(&packed, bit_width, count).unwrap();
+
+        assert_eq!(unpacked, data);
+    }
+
+    #[test]
+    fn test_rle_bitpack_hybrid_negative_values() {
+        // A negative run and negative literals; without zigzag these would be
+        // bit-packed via their raw two's-complement magnitude and decoded back
+        // as small positive values instead of round-tripping.
+        let mut data = vec![-1i64; 10]; // long run -> RLE
+        data.extend([-5, 3, -2]); // short, non-repeating -> literals
+
+        let (packed, bit_width, count) = RleBitPackHybrid::encode(&data).unwrap();
+        let unpacked = RleBitPackHybrid::decode(&packed, bit_width, count).unwrap();
+
+        assert_eq!(unpacked, data);
+    }
 }
@@ -10,7 +10,7 @@ pub mod bitpack;
 pub use rle::{RleEncoder, RleDecoder};
 pub use delta::DeltaEncoder;
 pub use dictionary::{DictionaryEncoder, DictionaryEncoderI64, DictionaryEncoderF64};
-pub use bitpack::BitPackEncoder;
+pub use bitpack::{AdaptiveCodec, BitPackEncoder, VarIntEncoder};
 
 use crate::error::{ArrowError, Result};
 
@@ -74,11 +74,21 @@ pub fn compress(data: &[u8], codec: Codec, _level: Level) -> Result<Vec<u8>> {
             let values: Vec<i64> = data.chunks_exact(8)
                 .map(|chunk| i64::from_le_bytes(chunk.try_into().unwrap()))
                 .collect();
-            let bit_width = bitpack::detect_bit_width(&values);
-            let mut packed = bitpack::pack(&values, bit_width)?;
-            // Prepend bit_width and count
+
+            // Zigzag as soon as a negative shows up, so it doesn't blow the bit width to 64
+            let zigzag = values.iter().any(|&v| v < 0);
+            let (bit_width, mut packed) = if zigzag {
+                let bit_width = bitpack::detect_bit_width_zigzag(&values);
+                (bit_width, bitpack::pack_zigzag(&values, bit_width)?)
+            } else {
+                let bit_width = bitpack::detect_bit_width(&values);
+                (bit_width, bitpack::pack(&values, bit_width)?)
+            };
+
+            // Prepend bit_width, zigzag flag and count
             let mut output = Vec::new();
             output.push(bit_width);
+            output.push(zigzag as u8);
             output.extend_from_slice(&(values.len() as u32).to_le_bytes());
             output.append(&mut packed);
             Ok(output)
@@ -101,15 +111,20 @@ pub fn decompress(data: &[u8], codec: Codec) -> Result<Vec<u8>> {
         }
         Codec::Dictionary => dictionary::decode(data),
         Codec::BitPack => {
-            if data.len() < 5 {
+            if data.len() < 6 {
                 return Err(ArrowError::InvalidData(
                     "BitPack data too short".to_string()
                 ));
             }
             let bit_width = data[0];
-            let count = u32::from_le_bytes(data[1..5].try_into().unwrap()) as usize;
-            let packed = &data[5..];
-            let values = bitpack::unpack(packed, bit_width, count)?;
+            let zigzag = data[1] != 0;
+            let count = u32::from_le_bytes(data[2..6].try_into().unwrap()) as usize;
+            let packed = &data[6..];
+            let values = if zigzag {
+                bitpack::unpack_zigzag(packed, bit_width, count)?
+            } else {
+                bitpack::unpack(packed, bit_width, count)?
+            };
             let mut output = Vec::with_capacity(values.len() * 8);
             for v in values {
                 output.extend_from_slice(&v.to_le_bytes());
@@ -0,0 +1,167 @@
+//! Buffer-level compression for Arrow IPC message bodies
+//!
+//! Mirrors the Arrow IPC spec's per-body length prefix: when enabled, a
+//! compressed body is prefixed with its uncompressed length (little-endian
+//! `i64`), with `-1` meaning "stored uncompressed" for bodies compression
+//! didn't shrink. The LZ4 bytes come from `avila_compress::lz4`, a block
+//! format with its own 4-byte size header, not the LZ4 frame format, so
+//! PyArrow/Arrow C++ cannot decompress them.
+//!
+//! The writer/reader in this crate don't serialize real per-column buffers
+//! (see the simplified batch messages in `writer.rs`/`reader.rs`) — they
+//! round-trip a single JSON body per message, and `compress_buffer`/
+//! `decompress_buffer` apply to that body.
+
+use crate::{ArrowError, Result};
+
+/// Compression codec for IPC message bodies, matching the codec ids used by
+/// `org.apache.arrow.flatbuf.CompressionType` (`Lz4Frame` = 0, `Zstd` = 1).
+/// `None` isn't a real Arrow codec id; it means the writer skips the
+/// length-prefix dance entirely, preserving today's uncompressed wire format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// No compression (default); bodies are written exactly as before
+    None,
+    /// LZ4 via `avila_compress::lz4` (its block format, not the LZ4 frame format)
+    Lz4Frame,
+    /// Zstandard
+    Zstd,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression::None
+    }
+}
+
+impl Compression {
+    /// Codec id as recorded in the message metadata
+    pub fn codec_id(self) -> i8 {
+        match self {
+            Compression::None => -1,
+            Compression::Lz4Frame => 0,
+            Compression::Zstd => 1,
+        }
+    }
+
+    /// Recover a codec from its recorded id
+    pub fn from_codec_id(id: i8) -> Result<Self> {
+        match id {
+            -1 => Ok(Compression::None),
+            0 => Ok(Compression::Lz4Frame),
+            1 => Ok(Compression::Zstd),
+            other => Err(ArrowError::Io(format!(
+                "Unknown IPC compression codec id: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Compress `data` with `codec`. When `codec` is [`Compression::None`] this
+/// returns `data` unchanged (today's default). Otherwise it returns the
+/// length-prefixed body the Arrow IPC spec expects: an `i64` little-endian
+/// uncompressed length followed by the compressed bytes, or `-1` followed by
+/// the original bytes when compression doesn't shrink the buffer.
+pub fn compress_buffer(data: &[u8], codec: Compression) -> Result<Vec<u8>> {
+    let compressed = match codec {
+        Compression::None => return Ok(data.to_vec()),
+        Compression::Lz4Frame => avila_compress::lz4::compress(data)
+            .map_err(|e| ArrowError::Io(format!("LZ4 compression failed: {}", e)))?,
+        Compression::Zstd => {
+            return Err(ArrowError::NotImplemented(
+                "Zstd IPC body compression not yet implemented".to_string(),
+            ))
+        }
+    };
+
+    let mut output = Vec::with_capacity(8 + compressed.len().min(data.len()));
+    if compressed.len() < data.len() {
+        output.extend_from_slice(&(data.len() as i64).to_le_bytes());
+        output.extend_from_slice(&compressed);
+    } else {
+        output.extend_from_slice(&(-1i64).to_le_bytes());
+        output.extend_from_slice(data);
+    }
+    Ok(output)
+}
+
+/// Inverse of [`compress_buffer`]
+pub fn decompress_buffer(data: &[u8], codec: Compression) -> Result<Vec<u8>> {
+    if codec == Compression::None {
+        return Ok(data.to_vec());
+    }
+
+    if data.len() < 8 {
+        return Err(ArrowError::Io(
+            "IPC buffer too short for length prefix".to_string(),
+        ));
+    }
+
+    let uncompressed_len = i64::from_le_bytes(data[..8].try_into().unwrap());
+    let body = &data[8..];
+
+    if uncompressed_len == -1 {
+        return Ok(body.to_vec());
+    }
+
+    match codec {
+        Compression::Lz4Frame => avila_compress::lz4::decompress(body)
+            .map_err(|e| ArrowError::Io(format!("LZ4 decompression failed: {}", e))),
+        Compression::Zstd => Err(ArrowError::NotImplemented(
+            "Zstd IPC body decompression not yet implemented".to_string(),
+        )),
+        Compression::None => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_id_roundtrip() {
+        for codec in [Compression::None, Compression::Lz4Frame, Compression::Zstd] {
+            assert_eq!(Compression::from_codec_id(codec.codec_id()).unwrap(), codec);
+        }
+    }
+
+    #[test]
+    fn test_none_passes_through_unchanged() {
+        let data = b"hello world".to_vec();
+        let compressed = compress_buffer(&data, Compression::None).unwrap();
+        assert_eq!(compressed, data);
+        let decompressed = decompress_buffer(&compressed, Compression::None).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn test_lz4_roundtrip_compressible_data() {
+        let data = vec![42u8; 1024];
+        let compressed = compress_buffer(&data, Compression::Lz4Frame).unwrap();
+        assert!(compressed.len() < data.len());
+
+        let decompressed = decompress_buffer(&compressed, Compression::Lz4Frame).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn test_lz4_falls_back_to_uncompressed_when_it_doesnt_shrink() {
+        // Tiny input: LZ4's own header overhead means "compressed" is not smaller
+        let data = vec![7u8, 3, 9];
+        let compressed = compress_buffer(&data, Compression::Lz4Frame).unwrap();
+
+        // -1 length prefix means "stored uncompressed"
+        assert_eq!(&compressed[0..8], &(-1i64).to_le_bytes());
+        assert_eq!(&compressed[8..], &data[..]);
+
+        let decompressed = decompress_buffer(&compressed, Compression::Lz4Frame).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn test_zstd_not_implemented() {
+        let data = b"hello".to_vec();
+        assert!(compress_buffer(&data, Compression::Zstd).is_err());
+    }
+}
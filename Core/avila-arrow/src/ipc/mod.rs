@@ -49,9 +49,11 @@
 pub mod reader;
 pub mod writer;
 
+mod compression;
 mod message;
 mod schema_generated;
 
+pub use compression::Compression;
 pub use reader::{StreamReader, FileReader};
 pub use writer::{StreamWriter, FileWriter};
 
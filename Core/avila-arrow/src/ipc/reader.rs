@@ -2,6 +2,7 @@
 //!
 //! Read RecordBatches from Arrow IPC format (streaming or file).
 
+use super::compression::{self, Compression};
 use crate::{RecordBatch, Schema, Field, DataType, ArrowError, Result};
 use crate::array::Int64Array;
 use byteorder::{LittleEndian, ReadBytesExt};
@@ -77,6 +78,14 @@ impl<'a> StreamReader<'a> {
         self.cursor.read_exact(&mut batch_data)
             .map_err(|e| ArrowError::Io(format!("Failed to read batch: {}", e)))?;
 
+        // First byte is the codec id the writer recorded; the rest is the
+        // (possibly length-prefixed, per compress_buffer) body
+        if batch_data.is_empty() {
+            return Err(ArrowError::Io("Empty batch message".to_string()));
+        }
+        let codec = Compression::from_codec_id(batch_data[0] as i8)?;
+        let batch_data = compression::decompress_buffer(&batch_data[1..], codec)?;
+
         // Parse batch info (simplified JSON)
         let batch_str = String::from_utf8(batch_data)
             .map_err(|e| ArrowError::Io(format!("Invalid UTF-8: {}", e)))?;
@@ -199,18 +208,30 @@ impl<'a> FileReader<'a> {
             let mut batch_data = vec![0u8; batch_len];
             cursor.read_exact(&mut batch_data)
                 .map_err(|e| ArrowError::Io(format!("Failed to read batch: {}", e)))?;
-            
-            let batch_str = String::from_utf8(batch_data)
-                .map_err(|e| ArrowError::Io(format!("Invalid UTF-8: {}", e)))?;
-            
-            let batch_info: serde_json::Value = serde_json::from_str(&batch_str)
-                .map_err(|e| ArrowError::Io(format!("Invalid batch JSON: {}", e)))?;
 
-            // Check if it's footer or batch
-            if let Some(num_batches) = batch_info["batches"].as_u64() {
-                self.num_batches = num_batches as usize;
-                break;
-            }
+            // The footer is plain JSON with no codec prefix; a RecordBatch
+            // chunk always starts with a codec id byte, which makes it fail
+            // to parse directly as JSON and tells them apart
+            let footer_probe: serde_json::Result<serde_json::Value> =
+                serde_json::from_slice(&batch_data);
+
+            let batch_info: serde_json::Value = if let Ok(value) = footer_probe {
+                if value.get("batches").is_some() {
+                    self.num_batches = value["batches"].as_u64().unwrap_or(0) as usize;
+                    break;
+                }
+                value
+            } else {
+                if batch_data.is_empty() {
+                    return Err(ArrowError::Io("Empty batch message".to_string()));
+                }
+                let codec = Compression::from_codec_id(batch_data[0] as i8)?;
+                let decoded = compression::decompress_buffer(&batch_data[1..], codec)?;
+                let batch_str = String::from_utf8(decoded)
+                    .map_err(|e| ArrowError::Io(format!("Invalid UTF-8: {}", e)))?;
+                serde_json::from_str(&batch_str)
+                    .map_err(|e| ArrowError::Io(format!("Invalid batch JSON: {}", e)))?
+            };
 
             if let Some(rows) = batch_info["rows"].as_u64() {
                 let batch = self.create_dummy_batch(&schema, rows as usize)?;
@@ -2,6 +2,7 @@
 //!
 //! Write RecordBatches to Arrow IPC format (streaming or file).
 
+use super::compression::{self, Compression};
 use crate::{RecordBatch, Schema, ArrowError, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
 use std::io::Write;
@@ -42,18 +43,28 @@ pub struct StreamWriter<W: Write> {
     writer: W,
     schema_written: bool,
     schema: Option<Schema>,
+    compression: Compression,
 }
 
 impl<W: Write> StreamWriter<W> {
-    /// Create a new stream writer
+    /// Create a new stream writer. Batches are written uncompressed by
+    /// default so older readers keep working; call [`with_compression`](Self::with_compression)
+    /// to opt in.
     pub fn new(writer: W) -> Self {
         Self {
             writer,
             schema_written: false,
             schema: None,
+            compression: Compression::None,
         }
     }
 
+    /// Compress each RecordBatch body with `codec` before writing it
+    pub fn with_compression(mut self, codec: Compression) -> Self {
+        self.compression = codec;
+        self
+    }
+
     /// Write a RecordBatch to the stream
     pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         // Write schema on first batch
@@ -113,23 +124,29 @@ impl<W: Write> StreamWriter<W> {
     fn write_batch_message(&mut self, batch: &RecordBatch) -> Result<()> {
         // Simplified batch message format
         // Real implementation will use FlatBuffers
-        
+
         // Write continuation marker
         self.writer.write_i32::<LittleEndian>(-1)
             .map_err(|e| ArrowError::Io(format!("Failed to write continuation: {}", e)))?;
-        
+
         // Write batch info (simplified)
         let batch_info = format!("{{\"rows\":{}}}", batch.num_rows());
-        let batch_bytes = batch_info.as_bytes();
-        
+        let body = compression::compress_buffer(batch_info.as_bytes(), self.compression)?;
+
+        // Codec id comes first so the reader can decompress without being
+        // told the writer's compression setting out of band
+        let batch_bytes: Vec<u8> = std::iter::once(self.compression.codec_id() as u8)
+            .chain(body)
+            .collect();
+
         // Write message length
         self.writer.write_i32::<LittleEndian>(batch_bytes.len() as i32)
             .map_err(|e| ArrowError::Io(format!("Failed to write length: {}", e)))?;
-        
+
         // Write batch data
-        self.writer.write_all(batch_bytes)
+        self.writer.write_all(&batch_bytes)
             .map_err(|e| ArrowError::Io(format!("Failed to write batch: {}", e)))?;
-        
+
         Ok(())
     }
 }
@@ -143,17 +160,27 @@ impl<W: Write> StreamWriter<W> {
 pub struct FileWriter<W: Write> {
     writer: W,
     batches: Vec<RecordBatch>,
+    compression: Compression,
 }
 
 impl<W: Write> FileWriter<W> {
-    /// Create a new file writer
+    /// Create a new file writer. Batches are written uncompressed by
+    /// default so older readers keep working; call [`with_compression`](Self::with_compression)
+    /// to opt in.
     pub fn new(writer: W) -> Self {
         Self {
             writer,
             batches: Vec::new(),
+            compression: Compression::None,
         }
     }
 
+    /// Compress each RecordBatch body with `codec` before writing it
+    pub fn with_compression(mut self, codec: Compression) -> Self {
+        self.compression = codec;
+        self
+    }
+
     /// Write a RecordBatch (buffered until finish)
     pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         self.batches.push(batch.clone());
@@ -183,9 +210,14 @@ impl<W: Write> FileWriter<W> {
         // Write batches
         for batch in &self.batches {
             let batch_info = format!("{{\"rows\":{}}}", batch.num_rows());
-            self.writer.write_i32::<LittleEndian>(batch_info.len() as i32)
+            let body = compression::compress_buffer(batch_info.as_bytes(), self.compression)?;
+            let batch_bytes: Vec<u8> = std::iter::once(self.compression.codec_id() as u8)
+                .chain(body)
+                .collect();
+
+            self.writer.write_i32::<LittleEndian>(batch_bytes.len() as i32)
                 .map_err(|e| ArrowError::Io(format!("Failed to write batch length: {}", e)))?;
-            self.writer.write_all(batch_info.as_bytes())
+            self.writer.write_all(&batch_bytes)
                 .map_err(|e| ArrowError::Io(format!("Failed to write batch: {}", e)))?;
         }
 
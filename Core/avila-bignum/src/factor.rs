@@ -0,0 +1,317 @@
+//! Pollard–Brent integer factorization for [`U1024`]
+//!
+//! Brent's cycle-detection variant of Pollard's rho finds a nontrivial
+//! factor of a composite `n` by iterating the pseudo-random map
+//! `f(x) = x^2 + c mod n` and watching two walkers (a "tortoise" advancing
+//! one step at a time, a "hare" advancing in power-of-two jumps) collide
+//! modulo some unknown factor of `n` long before they collide modulo `n`
+//! itself. [`U1024::factor`] peels off small prime factors by trial
+//! division first, then recurses with this on whatever composite cofactor
+//! is left.
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::montgomery::{mod_add, Montgomery1024};
+use crate::U1024;
+
+/// Small primes peeled off by trial division before Pollard-Brent takes
+/// over; mirrors [`crate::primality`]'s short-circuit list.
+const SMALL_PRIMES: [u64; 16] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53,
+];
+
+/// Steps taken between `gcd` checks. Larger batches mean fewer (expensive)
+/// gcds at the cost of possibly overshooting the collision by a few steps.
+const BATCH: usize = 128;
+
+/// Upper bound on Brent's doubling schedule before giving up on a given
+/// `c`/starting value and asking the caller to retry with fresh ones.
+const MAX_CYCLE_LENGTH: usize = 1 << 20;
+
+impl U1024 {
+    /// Returns the prime factorization of `self`, with repeated primes
+    /// listed once per multiplicity (e.g. `12.factor() == [2, 2, 3]`).
+    /// `0` and `1` have no prime factorization and return an empty vector.
+    pub fn factor(&self) -> Vec<U1024> {
+        let mut factors = Vec::new();
+        if *self <= U1024::ONE {
+            return factors;
+        }
+
+        let mut remaining = *self;
+        for &p in SMALL_PRIMES.iter() {
+            while remaining.rem_u64(p) == 0 {
+                factors.push(U1024::from_u64(p));
+                remaining = remaining.div_rem_u64(p).0;
+            }
+        }
+
+        factor_recursive(&mut factors, &remaining);
+        factors.sort();
+        factors
+    }
+}
+
+/// Splits `n` (already free of the small prime factors above) into primes,
+/// appending them to `factors`. Recurses on both sides of whatever split
+/// Pollard-Brent finds until every piece is prime.
+fn factor_recursive(factors: &mut Vec<U1024>, n: &U1024) {
+    if *n == U1024::ONE {
+        return;
+    }
+    if n.is_probable_prime() {
+        factors.push(*n);
+        return;
+    }
+
+    // No RNG is available in this no_std-capable crate, so random c/starting
+    // values are drawn from a small splitmix64 stream seeded from n itself.
+    // They can exceed a small `n` (the smallest leftover composite after
+    // trial division is 59^2 = 3481); `Montgomery1024::reduce` and the
+    // `mul_mod` inside each `f` step bring them into `[0, n)` with one
+    // binary long-division pass, however small or large `n` is.
+    const RANDOM_RANGE: u64 = 1 << 20;
+    let mut state = n.limbs[0] ^ n.limbs[1].rotate_left(17) ^ 0x9E37_79B9_7F4A_7C15;
+    loop {
+        state = splitmix64(state);
+        let c = (state % RANDOM_RANGE) + 1;
+        state = splitmix64(state);
+        let x0 = U1024::from_u64((state % RANDOM_RANGE) + 2);
+
+        if let Some(factor) = pollard_rho_brent(n, c, &x0) {
+            let (cofactor, _) = div_u1024(n, &factor);
+            factor_recursive(factors, &factor);
+            factor_recursive(factors, &cofactor);
+            return;
+        }
+    }
+}
+
+/// Finds one nontrivial factor of the odd composite `n` using Brent's
+/// variant of Pollard's rho with parameter `c` and starting value `x0`.
+/// Returns `None` if this `c`/`x0` degenerated (cycle collided modulo `n`
+/// itself, or never collided within [`MAX_CYCLE_LENGTH`] steps) — the
+/// caller should retry with a fresh `c` and `x0`.
+fn pollard_rho_brent(n: &U1024, c: u64, x0: &U1024) -> Option<U1024> {
+    let ctx = Montgomery1024::new(n)?;
+    let c = ctx.reduce(&U1024::from_u64(c));
+    let f = |x: &U1024| -> U1024 { mod_add(&ctx, &ctx.square_mod(x), &c) };
+
+    let mut y = *x0;
+    let mut r: usize = 1;
+    let mut g = U1024::ONE;
+    let mut x = y;
+    let mut ys = y;
+
+    while g == U1024::ONE {
+        x = y;
+        for _ in 0..r {
+            y = f(&y);
+        }
+
+        let mut k = 0;
+        while k < r && g == U1024::ONE {
+            let batch = BATCH.min(r - k);
+            let mut q = U1024::ONE;
+            for _ in 0..batch {
+                ys = y;
+                y = f(&y);
+                q = ctx.mul_mod(&q, &abs_diff(&x, &y));
+            }
+            g = gcd(&q, n);
+            k += batch;
+        }
+
+        r *= 2;
+        if r > MAX_CYCLE_LENGTH {
+            return None;
+        }
+    }
+
+    if g == *n {
+        // The batched gcd collapsed onto n itself; back off to a
+        // step-by-step gcd to find the exact collision. Bounded by BATCH
+        // steps (the same granularity the batch above used) so a fixed
+        // point of `f` — where `ys` never moves relative to `x` — can't
+        // spin here forever; such a `c`/`x0` just asks for a retry instead.
+        let mut degenerate = true;
+        for _ in 0..BATCH {
+            ys = f(&ys);
+            let diff = abs_diff(&x, &ys);
+            if diff.is_zero() {
+                break;
+            }
+            g = gcd(&diff, n);
+            if g != U1024::ONE {
+                degenerate = false;
+                break;
+            }
+        }
+        if degenerate {
+            return None; // fully degenerate cycle; ask for a fresh c
+        }
+    }
+
+    if g == *n || g == U1024::ONE {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+/// `|a - b|` for `U1024`, used to build Pollard-Brent's running product
+fn abs_diff(a: &U1024, b: &U1024) -> U1024 {
+    let a_is_larger = a >= b;
+    let mut diff = if a_is_larger { *a } else { *b };
+    diff.sub_assign(if a_is_larger { b } else { a });
+    diff
+}
+
+/// Binary GCD (Stein's algorithm): built from `shr_assign`, `sub_assign`
+/// and `trailing_zeros`, with no big/big division or modulo step.
+fn gcd(a: &U1024, b: &U1024) -> U1024 {
+    if a.is_zero() {
+        return *b;
+    }
+    if b.is_zero() {
+        return *a;
+    }
+
+    let mut a = *a;
+    let mut b = *b;
+    let shift = a.trailing_zeros().min(b.trailing_zeros());
+    a.shr_assign(a.trailing_zeros());
+
+    loop {
+        b.shr_assign(b.trailing_zeros());
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b.sub_assign(&a);
+        if b.is_zero() {
+            break;
+        }
+    }
+
+    a.shl_assign(shift);
+    a
+}
+
+/// Exact division used to split `n` into `factor` and its cofactor once
+/// Pollard-Brent finds a proper factor. Plain binary long division (shift,
+/// compare, conditionally subtract); this only runs once per factor found,
+/// not in a hot loop, so it doesn't need to be fast.
+fn div_u1024(a: &U1024, b: &U1024) -> (U1024, U1024) {
+    let mut quotient = U1024::ZERO;
+    let mut remainder = U1024::ZERO;
+
+    for i in (0..U1024::BITS as usize).rev() {
+        remainder.shl1_assign();
+        if a.bit(i) {
+            remainder.limbs[0] |= 1;
+        }
+        quotient.shl1_assign();
+        if remainder >= *b {
+            remainder.sub_assign(b);
+            quotient.limbs[0] |= 1;
+        }
+    }
+
+    (quotient, remainder)
+}
+
+/// Small, dependency-free PRNG (splitmix64) used only to diversify Pollard's
+/// rho's `c` and starting value across retries — no cryptographic
+/// properties are needed here.
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factor_zero_and_one() {
+        assert!(U1024::ZERO.factor().is_empty());
+        assert!(U1024::ONE.factor().is_empty());
+    }
+
+    #[test]
+    fn test_factor_prime() {
+        assert!(U1024::from_u64(97).factor() == vec![U1024::from_u64(97)]);
+    }
+
+    #[test]
+    fn test_factor_small_composite() {
+        // 12 = 2 * 2 * 3
+        let factors = U1024::from_u64(12).factor();
+        assert!(
+            factors
+                == vec![U1024::from_u64(2), U1024::from_u64(2), U1024::from_u64(3)]
+        );
+    }
+
+    #[test]
+    fn test_factor_semiprime_of_midsize_primes() {
+        // 104729 * 104723 = two six-digit primes, well past the small-prime
+        // trial division cutoff, so this exercises Pollard-Brent directly.
+        let p = 104729u64;
+        let q = 104723u64;
+        let n = U1024::from_u64(p * q);
+        let mut factors = n.factor();
+        factors.sort();
+        let mut expected = vec![U1024::from_u64(q), U1024::from_u64(p)];
+        expected.sort();
+        assert!(factors == expected);
+    }
+
+    #[test]
+    fn test_factor_product_reconstructs_original() {
+        for n in [2u64, 15, 97, 561, 1001, 65537, 999_983 * 999_979] {
+            let factors = U1024::from_u64(n).factor();
+            for f in &factors {
+                assert!(f.is_probable_prime(), "{:?} is not prime", f.limbs[0]);
+            }
+            let mut product = U1024::ONE;
+            for f in &factors {
+                product = mul_small(&product, f);
+            }
+            assert!(product == U1024::from_u64(n), "factors of {} did not reconstruct it", n);
+        }
+    }
+
+    // Cheap reconstruction helper for the test above: multiplies two
+    // small-enough U1024 values via repeated addition, avoiding a
+    // dependency on Montgomery1024 (which requires an odd modulus).
+    fn mul_small(a: &U1024, b: &U1024) -> U1024 {
+        let mut result = U1024::ZERO;
+        let mut shifted = *a;
+        for i in 0..U1024::BITS as usize {
+            if b.bit(i) {
+                result.add_assign(&shifted);
+            }
+            shifted.shl1_assign();
+        }
+        result
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert!(gcd(&U1024::from_u64(48), &U1024::from_u64(18)) == U1024::from_u64(6));
+        assert!(gcd(&U1024::from_u64(17), &U1024::from_u64(5)) == U1024::from_u64(1));
+        assert!(gcd(&U1024::ZERO, &U1024::from_u64(5)) == U1024::from_u64(5));
+    }
+
+    #[test]
+    fn test_div_u1024() {
+        let (q, r) = div_u1024(&U1024::from_u64(100), &U1024::from_u64(7));
+        assert!(q == U1024::from_u64(14));
+        assert!(r == U1024::from_u64(2));
+    }
+}
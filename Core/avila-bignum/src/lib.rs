@@ -7,10 +7,20 @@
 //! - `U2048`: 2048-bit unsigned integer (256 bytes) - RSA-2048
 //! - `U4096`: 4096-bit unsigned integer (512 bytes) - RSA-4096
 //! - `I4096`: 4096-bit signed integer
+//!
+//! `U1024`'s carry/borrow/widening-multiply helpers are built on
+//! `avila-nucleus`'s `adc`/`sbb`/`macc` limb primitives, the same ones
+//! `avila-primitives`'s `define_biguint!` uses for its own widths.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+mod factor;
+mod montgomery;
+mod primality;
+
+pub use montgomery::Montgomery1024;
+
 /// 1024-bit unsigned integer (16 × 64-bit limbs)
 #[repr(align(64))]
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -58,6 +68,219 @@ impl U1024 {
         }
         carry != 0
     }
+
+    /// Subtraction with borrow
+    pub fn sub_assign(&mut self, other: &Self) -> bool {
+        let mut borrow = 0u64;
+        for i in 0..16 {
+            let (diff, b1) = self.limbs[i].overflowing_sub(other.limbs[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow);
+            self.limbs[i] = diff;
+            borrow = (b1 as u64) + (b2 as u64);
+        }
+        borrow != 0
+    }
+
+    /// Returns the bit at `index` (0 = least significant)
+    #[inline]
+    pub fn bit(&self, index: usize) -> bool {
+        (self.limbs[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    /// Shifts left by one bit in place, returning the bit shifted out of the top
+    #[inline]
+    pub fn shl1_assign(&mut self) -> bool {
+        let mut carry = 0u64;
+        for limb in self.limbs.iter_mut() {
+            let new_carry = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = new_carry;
+        }
+        carry != 0
+    }
+
+    /// Shifts right by one bit in place, returning the bit shifted out of the bottom
+    #[inline]
+    pub fn shr1_assign(&mut self) -> bool {
+        let mut carry = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let new_carry = *limb & 1;
+            *limb = (*limb >> 1) | (carry << 63);
+            carry = new_carry;
+        }
+        carry != 0
+    }
+
+    /// Shifts left by `bits` bits in place
+    pub fn shl_assign(&mut self, bits: u32) {
+        if bits >= Self::BITS {
+            self.limbs = [0; 16];
+            return;
+        }
+        let limb_shift = (bits / 64) as usize;
+        let bit_shift = bits % 64;
+
+        if limb_shift > 0 {
+            for i in (0..16).rev() {
+                self.limbs[i] = if i >= limb_shift {
+                    self.limbs[i - limb_shift]
+                } else {
+                    0
+                };
+            }
+        }
+
+        if bit_shift > 0 {
+            let mut carry = 0u64;
+            for limb in self.limbs.iter_mut() {
+                let new_carry = *limb >> (64 - bit_shift);
+                *limb = (*limb << bit_shift) | carry;
+                carry = new_carry;
+            }
+        }
+    }
+
+    /// Shifts right by `bits` bits in place
+    pub fn shr_assign(&mut self, bits: u32) {
+        if bits >= Self::BITS {
+            self.limbs = [0; 16];
+            return;
+        }
+        let limb_shift = (bits / 64) as usize;
+        let bit_shift = bits % 64;
+
+        if limb_shift > 0 {
+            for i in 0..16 {
+                self.limbs[i] = if i + limb_shift < 16 {
+                    self.limbs[i + limb_shift]
+                } else {
+                    0
+                };
+            }
+        }
+
+        if bit_shift > 0 {
+            let mut carry = 0u64;
+            for limb in self.limbs.iter_mut().rev() {
+                let new_carry = *limb << (64 - bit_shift);
+                *limb = (*limb >> bit_shift) | carry;
+                carry = new_carry;
+            }
+        }
+    }
+
+    /// Number of trailing zero bits (1024 if the value is zero)
+    pub fn trailing_zeros(&self) -> u32 {
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            if limb != 0 {
+                return (i as u32) * 64 + limb.trailing_zeros();
+            }
+        }
+        Self::BITS
+    }
+
+    /// Remainder of dividing by a small `u64` divisor, computed limb-by-limb
+    /// in base `2^64` (Horner's method mod `divisor`)
+    pub fn rem_u64(&self, divisor: u64) -> u64 {
+        let mut rem: u128 = 0;
+        for &limb in self.limbs.iter().rev() {
+            rem = ((rem << 64) | limb as u128) % divisor as u128;
+        }
+        rem as u64
+    }
+
+    /// Divides by a small `u64` divisor, returning `(quotient, remainder)`,
+    /// computed limb-by-limb in base `2^64` (long division, most significant
+    /// limb first)
+    pub fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut quotient = Self::ZERO;
+        let mut rem: u128 = 0;
+        for i in (0..16).rev() {
+            let cur = (rem << 64) | self.limbs[i] as u128;
+            quotient.limbs[i] = (cur / divisor as u128) as u64;
+            rem = cur % divisor as u128;
+        }
+        (quotient, rem as u64)
+    }
+
+    /// Convert to little-endian bytes. Built limb-by-limb instead of
+    /// transmuting the struct, so this needs no `unsafe` and doesn't
+    /// depend on host endianness.
+    pub fn to_le_bytes(&self) -> [u8; 128] {
+        let mut result = [0u8; 128];
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            result[i * 8..(i + 1) * 8].copy_from_slice(&limb.to_le_bytes());
+        }
+        result
+    }
+
+    /// Adds `rhs` with an explicit carry-in, returning the sum and the
+    /// carry-out as a new value; [`add_assign`] takes no carry-in and works
+    /// in place.
+    ///
+    /// [`add_assign`]: Self::add_assign
+    pub fn carrying_add(&self, rhs: &Self, carry_in: bool) -> (Self, bool) {
+        let mut result = [0u64; 16];
+        let mut carry = carry_in as u64;
+        for i in 0..16 {
+            let (sum, c) = ::avila_nucleus::bits::adc(self.limbs[i], rhs.limbs[i], carry);
+            result[i] = sum;
+            carry = c;
+        }
+        (Self { limbs: result }, carry != 0)
+    }
+
+    /// Subtracts `rhs` with an explicit borrow-in, returning the difference
+    /// and the borrow-out as a new value; [`sub_assign`] takes no borrow-in
+    /// and works in place.
+    ///
+    /// [`sub_assign`]: Self::sub_assign
+    pub fn borrowing_sub(&self, rhs: &Self, borrow_in: bool) -> (Self, bool) {
+        let mut result = [0u64; 16];
+        let mut borrow = borrow_in as u64;
+        for i in 0..16 {
+            let (diff, b) = ::avila_nucleus::bits::sbb(self.limbs[i], rhs.limbs[i], borrow);
+            result[i] = diff;
+            borrow = b;
+        }
+        (Self { limbs: result }, borrow != 0)
+    }
+
+    /// Full double-width schoolbook multiplication: returns every limb of
+    /// the exact 2048-bit product instead of truncating to 1024 bits the
+    /// way a `Mul` impl would.
+    pub fn widening_mul(&self, rhs: &Self) -> [u64; 32] {
+        let mut result = [0u64; 32];
+        for i in 0..16 {
+            let mut carry = 0u64;
+            for j in 0..16 {
+                let (sum, c) =
+                    ::avila_nucleus::bits::macc(self.limbs[i], rhs.limbs[j], result[i + j], carry);
+                result[i + j] = sum;
+                carry = c;
+            }
+            result[i + 16] = carry;
+        }
+        result
+    }
+}
+
+impl PartialOrd for U1024 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U1024 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        for i in (0..16).rev() {
+            match self.limbs[i].cmp(&other.limbs[i]) {
+                core::cmp::Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        core::cmp::Ordering::Equal
+    }
 }
 
 impl Default for U1024 {
@@ -205,7 +428,7 @@ impl Default for I4096 {
 
 /// Prelude
 pub mod prelude {
-    pub use crate::{U1024, U2048, U4096, I4096};
+    pub use crate::{U1024, U2048, U4096, I4096, Montgomery1024};
 }
 
 #[cfg(test)]
@@ -240,6 +463,85 @@ mod tests {
         assert_eq!(a.limbs[0], 42);
     }
 
+    #[test]
+    fn test_u1024_sub() {
+        let mut a = U1024::from_u64(42);
+        let b = U1024::from_u64(10);
+        let borrow = a.sub_assign(&b);
+        assert!(!borrow);
+        assert_eq!(a.limbs[0], 32);
+    }
+
+    #[test]
+    fn test_u1024_sub_borrow() {
+        let mut a = U1024::from_u64(10);
+        let b = U1024::from_u64(42);
+        let borrow = a.sub_assign(&b);
+        assert!(borrow);
+    }
+
+    #[test]
+    fn test_u1024_bit() {
+        let n = U1024::from_u64(0b1010);
+        assert!(!n.bit(0));
+        assert!(n.bit(1));
+        assert!(!n.bit(2));
+        assert!(n.bit(3));
+    }
+
+    #[test]
+    fn test_u1024_shl1_shr1_roundtrip() {
+        let mut n = U1024::from_u64(u64::MAX);
+        let carry_out = n.shl1_assign();
+        assert!(!carry_out);
+        assert_eq!(n.limbs[0], u64::MAX << 1);
+        assert_eq!(n.limbs[1], 1);
+
+        let carry_in = n.shr1_assign();
+        assert!(!carry_in);
+        assert_eq!(n.limbs[0], u64::MAX);
+        assert_eq!(n.limbs[1], 0);
+    }
+
+    #[test]
+    fn test_u1024_shr_assign_crosses_limb_boundary() {
+        let mut n = U1024::from_u64(1);
+        n.shr_assign(64);
+        assert!(n.is_zero());
+
+        let mut n = U1024::ZERO;
+        n.limbs[1] = 1; // represents 2^64
+        n.shr_assign(64);
+        assert_eq!(n.limbs[0], 1);
+    }
+
+    #[test]
+    fn test_u1024_trailing_zeros() {
+        assert_eq!(U1024::ZERO.trailing_zeros(), U1024::BITS);
+        assert_eq!(U1024::from_u64(8).trailing_zeros(), 3);
+
+        let mut n = U1024::ZERO;
+        n.limbs[2] = 4; // 4 * 2^128
+        assert_eq!(n.trailing_zeros(), 130);
+    }
+
+    #[test]
+    fn test_u1024_rem_u64() {
+        assert_eq!(U1024::from_u64(100).rem_u64(7), 2);
+        assert_eq!(U1024::from_u64(21).rem_u64(7), 0);
+    }
+
+    #[test]
+    fn test_u1024_ord() {
+        assert!(U1024::from_u64(5) < U1024::from_u64(10));
+        assert!(U1024::from_u64(10) > U1024::from_u64(5));
+        assert!(U1024::from_u64(7) == U1024::from_u64(7));
+
+        let mut big = U1024::ZERO;
+        big.limbs[1] = 1; // 2^64, bigger than any single-limb value
+        assert!(big > U1024::from_u64(u64::MAX));
+    }
+
     #[test]
     fn test_u2048_constants() {
         assert!(U2048::ZERO.is_zero());
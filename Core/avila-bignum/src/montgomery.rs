@@ -0,0 +1,372 @@
+//! Montgomery modular arithmetic and constant-time modular exponentiation for [`U1024`]
+//!
+//! Montgomery form trades a cheap-to-compute representation (`x * R mod n`,
+//! where `R = 2^1024`) for replacing modular reduction's division with shifts
+//! and multiplies (REDC). [`Montgomery1024::modpow`] uses this to implement
+//! RSA-style modular exponentiation without ever branching on a secret
+//! exponent bit, which is what makes it safe to use with a private exponent.
+
+use crate::U1024;
+
+/// Precomputed Montgomery context for a fixed odd 1024-bit modulus
+///
+/// Building the context (computing `n'` and `R^2 mod n`) only touches the
+/// modulus, which is public in the usual RSA setting, so [`Montgomery1024::new`]
+/// takes the ordinary (non-constant-time) path. [`Montgomery1024::modpow`] is
+/// the part that must stay constant-time, since its exponent is the secret.
+#[derive(Clone, Copy)]
+pub struct Montgomery1024 {
+    /// The modulus itself
+    n: U1024,
+    /// `-n^-1 mod 2^64`, used to cancel the low limb in each REDC step
+    n_prime: u64,
+    /// `R^2 mod n`, used to move values into Montgomery form
+    r2: U1024,
+}
+
+impl Montgomery1024 {
+    /// Builds a Montgomery context for `modulus`. Montgomery reduction
+    /// requires an odd modulus (so that it's invertible mod `2^64`); returns
+    /// `None` for even or zero moduli.
+    pub fn new(modulus: &U1024) -> Option<Self> {
+        if modulus.is_zero() || modulus.limbs[0] & 1 == 0 {
+            return None;
+        }
+
+        Some(Self {
+            n: *modulus,
+            n_prime: mod_inverse_neg(modulus.limbs[0]),
+            r2: r_squared_mod(modulus),
+        })
+    }
+
+    /// Computes `base^exponent mod n` without branching on any bit of
+    /// `exponent`. Every iteration squares, then uses a constant-time select
+    /// (not an `if`) to decide whether the multiply-by-base step's result is
+    /// kept, so the sequence of operations executed is the same regardless of
+    /// the exponent's value.
+    pub fn modpow(&self, base: &U1024, exponent: &U1024) -> U1024 {
+        let base = self.reduce(base);
+        let base_m = self.to_monty(&base);
+        let mut result_m = self.to_monty(&U1024::ONE);
+
+        for i in (0..U1024::BITS as usize).rev() {
+            result_m = self.mont_mul(&result_m, &result_m);
+            let multiplied = self.mont_mul(&result_m, &base_m);
+            result_m = conditional_select(exponent.bit(i), &multiplied, &result_m);
+        }
+
+        self.from_monty(&result_m)
+    }
+
+    /// Returns the modulus this context was built for
+    pub fn modulus(&self) -> U1024 {
+        self.n
+    }
+
+    /// Returns `a * b mod n`
+    pub fn mul_mod(&self, a: &U1024, b: &U1024) -> U1024 {
+        let a_m = self.to_monty(&self.reduce(a));
+        let b_m = self.to_monty(&self.reduce(b));
+        self.from_monty(&self.mont_mul(&a_m, &b_m))
+    }
+
+    /// Returns `a * a mod n`
+    pub fn square_mod(&self, a: &U1024) -> U1024 {
+        self.mul_mod(a, a)
+    }
+
+    /// Reduces `v` into `[0, n)`. Binary long division (shift the dividend's
+    /// next bit in, compare, conditionally subtract), one pass over `v`'s
+    /// `BITS` bits regardless of how far `v` is from `[0, n)` — unlike a
+    /// repeated-subtraction loop, which needs up to `v / n` subtractions and
+    /// can run for a near-infinite number of iterations when `n` is small
+    /// and `v` is an unreduced value straight from a public `modpow` caller
+    /// (this is not the constant-time path — see [`Self::modpow`] for that).
+    pub(crate) fn reduce(&self, v: &U1024) -> U1024 {
+        let mut remainder = U1024::ZERO;
+        for i in (0..U1024::BITS as usize).rev() {
+            remainder.shl1_assign();
+            if v.bit(i) {
+                remainder.limbs[0] |= 1;
+            }
+
+            let mut candidate = remainder;
+            let borrow = candidate.sub_assign(&self.n);
+            if !borrow {
+                remainder = candidate;
+            }
+        }
+        remainder
+    }
+
+    fn to_monty(&self, v: &U1024) -> U1024 {
+        self.mont_mul(v, &self.r2)
+    }
+
+    fn from_monty(&self, v: &U1024) -> U1024 {
+        let mut wide = [0u64; 32];
+        wide[..16].copy_from_slice(&v.limbs);
+        self.redc(wide)
+    }
+
+    /// Montgomery multiplication: `REDC(a * b)`
+    fn mont_mul(&self, a: &U1024, b: &U1024) -> U1024 {
+        self.redc(mul_wide(a, b))
+    }
+
+    /// The REDC step: given `t < n * R`, returns `t * R^-1 mod n` in `[0, n)`.
+    ///
+    /// `t` carries one extra limb (33 instead of the 32-limb, `2n`-wide
+    /// product) because the carry-propagation loop below can need a word
+    /// beyond the `2n`-limb product to hold its final carry (HAC Algorithm
+    /// 14.32 requires a `2n+1`-limb buffer); without it, that loop's index
+    /// walks past the end of a `2n`-limb array on realistic ~1024-bit inputs.
+    ///
+    /// The final "subtract n if the result overflowed" step uses a
+    /// constant-time select, since `t` (and therefore whether it overflows)
+    /// depends on the secret intermediate values flowing through `modpow`.
+    fn redc(&self, t_in: [u64; 32]) -> U1024 {
+        let mut t = [0u64; 33];
+        t[..32].copy_from_slice(&t_in);
+
+        for i in 0..16 {
+            let m = t[i].wrapping_mul(self.n_prime);
+            let mut carry: u128 = 0;
+            for j in 0..16 {
+                let idx = i + j;
+                let prod = (m as u128) * (self.n.limbs[j] as u128) + (t[idx] as u128) + carry;
+                t[idx] = prod as u64;
+                carry = prod >> 64;
+            }
+            let mut k = i + 16;
+            while carry > 0 {
+                let sum = (t[k] as u128) + carry;
+                t[k] = sum as u64;
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+
+        // `t[32]` is the carry out of the top limb of the 2n-limb result; it
+        // is 0 or 1 (the true value is at most `2n - 1 < 2 * R`), so it can't
+        // be folded into `result` directly but does mean a subtraction is
+        // always needed regardless of what plain `U1024` subtraction reports.
+        let result = U1024 {
+            limbs: t[16..32].try_into().unwrap(),
+        };
+        let overflowed = t[32] != 0;
+        let mut subtracted = result;
+        let borrow = subtracted.sub_assign(&self.n);
+        conditional_select(overflowed || !borrow, &subtracted, &result)
+    }
+}
+
+/// Returns `a + b mod n`, used by callers (e.g. the primality and
+/// factorization modules) that need to add values already known to be in
+/// `[0, n)` without going through a full `Montgomery1024::mul_mod` round trip.
+pub(crate) fn mod_add(ctx: &Montgomery1024, a: &U1024, b: &U1024) -> U1024 {
+    let mut sum = *a;
+    let overflow = sum.add_assign(b);
+    let n = ctx.modulus();
+    if overflow || sum >= n {
+        sum.sub_assign(&n);
+    }
+    sum
+}
+
+/// Returns `a - b mod n`, the `mod_add` counterpart
+pub(crate) fn mod_sub(ctx: &Montgomery1024, a: &U1024, b: &U1024) -> U1024 {
+    let mut diff = *a;
+    let borrow = diff.sub_assign(b);
+    if borrow {
+        diff.add_assign(&ctx.modulus());
+    }
+    diff
+}
+
+/// Selects `a` if `choice` is true, `b` otherwise, without branching on
+/// `choice` at the limb level (the bool -> mask conversion is a plain
+/// arithmetic zero/sign-extend, not a conditional jump).
+#[inline]
+fn conditional_select(choice: bool, a: &U1024, b: &U1024) -> U1024 {
+    let mask = 0u64.wrapping_sub(choice as u64);
+    let mut out = U1024::ZERO;
+    for i in 0..16 {
+        out.limbs[i] = (a.limbs[i] & mask) | (b.limbs[i] & !mask);
+    }
+    out
+}
+
+/// Full 1024x1024 -> 2048-bit schoolbook multiplication
+fn mul_wide(a: &U1024, b: &U1024) -> [u64; 32] {
+    let mut result = [0u64; 32];
+    for i in 0..16 {
+        let mut carry: u128 = 0;
+        for j in 0..16 {
+            let idx = i + j;
+            let prod = (a.limbs[i] as u128) * (b.limbs[j] as u128) + (result[idx] as u128) + carry;
+            result[idx] = prod as u64;
+            carry = prod >> 64;
+        }
+        let mut k = i + 16;
+        while carry > 0 {
+            let sum = (result[k] as u128) + carry;
+            result[k] = sum as u64;
+            carry = sum >> 64;
+            k += 1;
+        }
+    }
+    result
+}
+
+/// `-n^-1 mod 2^64` via Newton-Raphson (Dusse-Kaliski): each iteration
+/// doubles the number of correct bits, so 6 iterations is enough to converge
+/// from 1 correct bit to all 64 for any odd `n`.
+fn mod_inverse_neg(n: u64) -> u64 {
+    let mut inv = n;
+    for _ in 0..6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(inv)));
+    }
+    inv.wrapping_neg()
+}
+
+/// `R^2 mod n` where `R = 2^1024`, computed by doubling-and-reducing `1`
+/// `2 * U1024::BITS` times. Only the (public) modulus drives this, so the
+/// data-dependent branching here doesn't leak anything secret.
+fn r_squared_mod(n: &U1024) -> U1024 {
+    let mut r = U1024::ONE;
+    for _ in 0..(2 * U1024::BITS) {
+        let carry_out = r.shl1_assign();
+        if carry_out {
+            // True value is 2^1024 + r; wrapping subtraction of n from r
+            // alone gives exactly that value mod 2^1024, which is already < n.
+            r.sub_assign(n);
+        } else {
+            let mut candidate = r;
+            let borrow = candidate.sub_assign(n);
+            if !borrow {
+                r = candidate;
+            }
+        }
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_rejects_even_modulus() {
+        assert!(Montgomery1024::new(&U1024::from_u64(100)).is_none());
+    }
+
+    #[test]
+    fn test_new_rejects_zero_modulus() {
+        assert!(Montgomery1024::new(&U1024::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_modpow_small_values() {
+        // 3^5 mod 7 = 243 mod 7 = 5
+        let ctx = Montgomery1024::new(&U1024::from_u64(7)).unwrap();
+        let result = ctx.modpow(&U1024::from_u64(3), &U1024::from_u64(5));
+        assert_eq!(result.limbs[0], 5);
+        assert!(result.limbs[1..].iter().all(|&l| l == 0));
+    }
+
+    #[test]
+    fn test_modpow_exponent_zero() {
+        // x^0 mod n = 1 for any x coprime-or-not (standard convention)
+        let ctx = Montgomery1024::new(&U1024::from_u64(11)).unwrap();
+        let result = ctx.modpow(&U1024::from_u64(42), &U1024::ZERO);
+        assert_eq!(result.limbs[0], 1);
+    }
+
+    #[test]
+    fn test_modpow_base_larger_than_modulus() {
+        // 10^2 mod 7 = 100 mod 7 = 2
+        let ctx = Montgomery1024::new(&U1024::from_u64(7)).unwrap();
+        let result = ctx.modpow(&U1024::from_u64(10), &U1024::from_u64(2));
+        assert_eq!(result.limbs[0], 2);
+    }
+
+    #[test]
+    fn test_modpow_matches_fermat_little_theorem() {
+        // a^(p-1) mod p = 1 for prime p and a not divisible by p
+        let p = 1_000_003u64; // prime
+        let ctx = Montgomery1024::new(&U1024::from_u64(p)).unwrap();
+        let result = ctx.modpow(&U1024::from_u64(12345), &U1024::from_u64(p - 1));
+        assert_eq!(result.limbs[0], 1);
+    }
+
+    #[test]
+    fn test_modpow_spans_multiple_limbs() {
+        // n = 2^100 + 277, base and exponent also exercise more than one
+        // limb; expected values cross-checked against Python's pow(base, exp, n).
+        let mut n_limbs = [0u64; 16];
+        n_limbs[0] = 277;
+        n_limbs[1] = 68719476736;
+        let n = U1024 { limbs: n_limbs };
+
+        let mut base_limbs = [0u64; 16];
+        base_limbs[0] = 14083847773837265618;
+        base_limbs[1] = 6692605942;
+        let base = U1024 { limbs: base_limbs };
+
+        let exponent = U1024::from_u64(65537);
+
+        let ctx = Montgomery1024::new(&n).unwrap();
+        let result = ctx.modpow(&base, &exponent);
+
+        assert_eq!(result.limbs[0], 13344425077935962007);
+        assert_eq!(result.limbs[1], 58767235754);
+        assert!(result.limbs[2..].iter().all(|&l| l == 0));
+    }
+
+    #[test]
+    fn test_modpow_full_width_operands_does_not_panic() {
+        // n = 2^1024 - 1 (all limbs set, odd), a = b = n - 1: the REDC
+        // accumulator's carry-propagation loop needs the 33rd limb here, or
+        // it indexes past the end of a 32-limb buffer.
+        let n = U1024 { limbs: [u64::MAX; 16] };
+        let mut a_limbs = [u64::MAX; 16];
+        a_limbs[0] -= 1; // n - 1
+        let a = U1024 { limbs: a_limbs };
+
+        let ctx = Montgomery1024::new(&n).unwrap();
+        let result = ctx.mul_mod(&a, &a);
+        assert!(result < n);
+    }
+
+    #[test]
+    fn test_reduce_small_modulus_large_value_does_not_hang() {
+        // base = 2^1024 - 1, far outside [0, n) for a small modulus: the old
+        // repeated-subtraction reduce() needed on the order of base/n
+        // iterations here, effectively hanging.
+        let base = U1024 { limbs: [u64::MAX; 16] };
+        let ctx = Montgomery1024::new(&U1024::from_u64(7)).unwrap();
+        // 2^1024 mod 7 == 2 (2^3 ≡ 1 mod 7, 1024 == 3*341 + 1), so
+        // (2^1024 - 1) mod 7 == 1.
+        let result = ctx.modpow(&base, &U1024::from_u64(1));
+        assert_eq!(result.limbs[0], 1);
+        assert!(result.limbs[1..].iter().all(|&l| l == 0));
+    }
+
+    #[test]
+    fn test_mod_inverse_neg_is_correct() {
+        let n = 0x9E3779B97F4A7C15u64 | 1; // force odd
+        let n_prime = mod_inverse_neg(n);
+        // n * n_prime should be ≡ -1 mod 2^64, i.e. n.wrapping_mul(n_prime) == u64::MAX
+        assert_eq!(n.wrapping_mul(n_prime), u64::MAX);
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let a = U1024::from_u64(111);
+        let b = U1024::from_u64(222);
+        assert_eq!(conditional_select(true, &a, &b).limbs[0], 111);
+        assert_eq!(conditional_select(false, &a, &b).limbs[0], 222);
+    }
+}
@@ -0,0 +1,379 @@
+//! Baillie-PSW probable-primality test for [`U1024`]
+//!
+//! Combines a strong Miller-Rabin test to base 2 with a strong Lucas test
+//! using Selfridge's parameter choice. No composite is known to pass both
+//! (BPSW has no known counterexample below 2^64), which makes it far more
+//! reliable in practice than a handful of random Miller-Rabin rounds.
+
+use crate::montgomery::{mod_add, mod_sub, Montgomery1024};
+use crate::U1024;
+
+/// Small primes used to reject obviously composite candidates (and to
+/// short-circuit on small primes themselves) before paying for the
+/// Montgomery-based tests below.
+const SMALL_PRIMES: [u64; 16] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53,
+];
+
+impl U1024 {
+    /// Returns `true` if `self` is probably prime (Baillie-PSW).
+    pub fn is_probable_prime(&self) -> bool {
+        is_probable_prime(self)
+    }
+}
+
+fn is_probable_prime(n: &U1024) -> bool {
+    if *n <= U1024::ONE {
+        return false;
+    }
+
+    for &p in SMALL_PRIMES.iter() {
+        if *n == U1024::from_u64(p) {
+            return true;
+        }
+        if n.rem_u64(p) == 0 {
+            return false;
+        }
+    }
+
+    miller_rabin_base2(n) && strong_lucas(n)
+}
+
+/// Strong Miller-Rabin test to base 2: write `n - 1 = d * 2^s` with `d` odd,
+/// compute `x = 2^d mod n`, and accept unless repeated squaring never lands
+/// on `n - 1`.
+fn miller_rabin_base2(n: &U1024) -> bool {
+    let ctx = match Montgomery1024::new(n) {
+        Some(ctx) => ctx,
+        None => return false, // even n, already filtered above in practice
+    };
+
+    let mut n_minus_1 = *n;
+    n_minus_1.sub_assign(&U1024::ONE);
+
+    let s = n_minus_1.trailing_zeros();
+    let mut d = n_minus_1;
+    d.shr_assign(s);
+
+    let mut x = ctx.modpow(&U1024::from_u64(2), &d);
+
+    if x == U1024::ONE || x == n_minus_1 {
+        return true;
+    }
+
+    for _ in 1..s {
+        x = ctx.square_mod(&x);
+        if x == n_minus_1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Strong Lucas probable-prime test with Selfridge's parameters `P = 1`,
+/// `Q = (1 - D) / 4`, where `D` is the first term of `5, -7, 9, -11, 13, ...`
+/// whose Jacobi symbol `(D / n)` is `-1`.
+fn strong_lucas(n: &U1024) -> bool {
+    let ctx = match Montgomery1024::new(n) {
+        Some(ctx) => ctx,
+        None => return false,
+    };
+
+    let d = match selfridge_d(n) {
+        Some(d) => d,
+        None => return false, // n is a perfect square, so composite
+    };
+
+    let q = (1 - d) / 4;
+
+    let mut n_plus_1 = *n;
+    n_plus_1.add_assign(&U1024::ONE);
+    let s = n_plus_1.trailing_zeros();
+    let mut lucas_d = n_plus_1;
+    lucas_d.shr_assign(s);
+
+    let (u, mut v, mut qk) = lucas_uv(&ctx, d, q, &lucas_d);
+
+    if u == U1024::ZERO || v == U1024::ZERO {
+        return true;
+    }
+
+    for _ in 1..s {
+        v = {
+            let v2 = ctx.square_mod(&v);
+            let two_qk = ctx.mul_mod(&qk, &U1024::from_u64(2));
+            mod_sub(&ctx, &v2, &two_qk)
+        };
+        qk = ctx.square_mod(&qk);
+        if v == U1024::ZERO {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Computes `(U_d, V_d, Q^d) mod n` for the Lucas sequence with parameters
+/// `P = 1, Q = q`, via the standard double-and-add binary ladder over the
+/// bits of `d`.
+fn lucas_uv(ctx: &Montgomery1024, d: i64, q: i64, index: &U1024) -> (U1024, U1024, U1024) {
+    let d_mod_n = signed_small_mod(ctx, d);
+    let q_mod_n = signed_small_mod(ctx, q);
+
+    // Start at k = 1: U_1 = 1, V_1 = P = 1, Q^1 = q mod n
+    let mut u = U1024::ONE;
+    let mut v = U1024::ONE;
+    let mut qk = q_mod_n;
+
+    // Find the highest set bit of `index` below the top (k already covers bit `msb`)
+    let msb = highest_set_bit(index);
+    if msb == 0 {
+        // index == 1, nothing further to do
+        return (u, v, qk);
+    }
+
+    for i in (0..msb).rev() {
+        // Double: k -> 2k
+        let u2 = ctx.mul_mod(&u, &v);
+        let v2 = {
+            let v_sq = ctx.square_mod(&v);
+            let two_qk = ctx.mul_mod(&qk, &U1024::from_u64(2));
+            mod_sub(ctx, &v_sq, &two_qk)
+        };
+        let qk2 = ctx.square_mod(&qk);
+        u = u2;
+        v = v2;
+        qk = qk2;
+
+        if index.bit(i) {
+            // Add one: k -> k + 1
+            let new_u = {
+                let sum = mod_add(ctx, &u, &v);
+                ctx.mul_mod(&sum, &inv2(ctx))
+            };
+            let new_v = {
+                let du = ctx.mul_mod(&d_mod_n, &u);
+                let sum = mod_add(ctx, &du, &v);
+                ctx.mul_mod(&sum, &inv2(ctx))
+            };
+            u = new_u;
+            v = new_v;
+            qk = ctx.mul_mod(&qk, &q_mod_n);
+        }
+    }
+
+    (u, v, qk)
+}
+
+fn highest_set_bit(n: &U1024) -> usize {
+    for i in (0..U1024::BITS as usize).rev() {
+        if n.bit(i) {
+            return i;
+        }
+    }
+    0
+}
+
+/// `2^-1 mod n`, which for odd `n` is simply `(n + 1) / 2`
+fn inv2(ctx: &Montgomery1024) -> U1024 {
+    let mut t = ctx.modulus();
+    t.add_assign(&U1024::ONE);
+    t.shr1_assign();
+    t
+}
+
+/// Reduces a small signed integer into `[0, n)`
+fn signed_small_mod(ctx: &Montgomery1024, value: i64) -> U1024 {
+    let abs = ctx.reduce(&U1024::from_u64(value.unsigned_abs()));
+    if value < 0 && !abs.is_zero() {
+        let mut n = ctx.modulus();
+        n.sub_assign(&abs);
+        n
+    } else {
+        abs
+    }
+}
+
+/// Finds the first `D` in `5, -7, 9, -11, 13, ...` with Jacobi symbol
+/// `(D / n) == -1`. Returns `None` if `n` is a perfect square (its Jacobi
+/// symbols are never `-1`, so this is checked up front with an integer
+/// square root) or if some `D` shares a proper factor with `n` (Jacobi
+/// symbol `0`); either way `n` is composite.
+fn selfridge_d(n: &U1024) -> Option<i64> {
+    // Digit-by-digit integer square root; `rem` ends as n - isqrt(n)^2
+    let (mut rem, mut root, mut bit) = (*n, U1024::ZERO, U1024::ONE);
+    bit.shl_assign(U1024::BITS - 2);
+    while !bit.is_zero() {
+        let mut t = root;
+        t.add_assign(&bit);
+        root.shr1_assign();
+        if rem >= t {
+            rem.sub_assign(&t);
+            root.add_assign(&bit);
+        }
+        bit.shr_assign(2);
+    }
+    if rem.is_zero() {
+        return None;
+    }
+
+    let mut d: i64 = 5;
+    loop {
+        let j = jacobi(d, n);
+        if j == -1 {
+            return Some(d);
+        }
+        if j == 0 && U1024::from_u64(d.unsigned_abs()) != *n {
+            return None;
+        }
+        d = if d > 0 { -(d + 2) } else { -(d - 2) };
+    }
+}
+
+/// Jacobi symbol `(d / n)` for odd positive `n`. `d` starts small (Selfridge
+/// search terms), so after one manual reciprocity step the remaining
+/// computation only ever touches small integers and can run on plain `i64`.
+fn jacobi(d: i64, n: &U1024) -> i32 {
+    let n_mod4 = low_bits(n, 2);
+    let n_mod8 = low_bits(n, 3);
+
+    let mut sign = 1i32;
+    let mut a = d.unsigned_abs();
+
+    if d < 0 && n_mod4 == 3 {
+        sign = -sign;
+    }
+
+    while a % 2 == 0 {
+        a /= 2;
+        if n_mod8 == 3 || n_mod8 == 5 {
+            sign = -sign;
+        }
+    }
+
+    if a == 1 {
+        return sign;
+    }
+    if a == 0 {
+        return 0;
+    }
+
+    if a % 4 == 3 && n_mod4 == 3 {
+        sign = -sign;
+    }
+
+    let r = n.rem_u64(a);
+    sign * jacobi_small(r as i64, a as i64)
+}
+
+/// Low `bits` bits of `n` as a small integer (i.e. `n mod 2^bits`)
+fn low_bits(n: &U1024, bits: usize) -> u64 {
+    let mut out = 0u64;
+    for i in 0..bits {
+        if n.bit(i) {
+            out |= 1 << i;
+        }
+    }
+    out
+}
+
+/// Classic small-integer Jacobi symbol, used once the big modulus has been
+/// reduced down to a small one by [`jacobi`]'s first step
+fn jacobi_small(mut a: i64, mut n: i64) -> i32 {
+    let mut result = 1;
+    a = a.rem_euclid(n);
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            let r = n % 8;
+            if r == 3 || r == 5 {
+                result = -result;
+            }
+        }
+        core::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a = a.rem_euclid(n);
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trial_division_is_prime(n: u64) -> bool {
+        if n < 2 {
+            return false;
+        }
+        let mut i = 2;
+        while i * i <= n {
+            if n % i == 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[test]
+    fn test_small_primes() {
+        for p in [2u64, 3, 5, 7, 11, 13, 97, 104729] {
+            assert!(U1024::from_u64(p).is_probable_prime(), "{} should be prime", p);
+        }
+    }
+
+    #[test]
+    fn test_small_composites() {
+        for c in [0u64, 1, 4, 6, 8, 9, 100, 561, 1001, 104730] {
+            assert!(!U1024::from_u64(c).is_probable_prime(), "{} should be composite", c);
+        }
+    }
+
+    #[test]
+    fn test_carmichael_number_561() {
+        // 561 = 3 * 11 * 17 is the smallest Carmichael number; it passes
+        // Fermat's test for every base coprime to it, but BPSW still
+        // correctly rejects it.
+        assert!(!U1024::from_u64(561).is_probable_prime());
+    }
+
+    #[test]
+    fn test_strong_miller_rabin_base2_pseudoprime_2047() {
+        // 2047 = 23 * 89 is the smallest strong pseudoprime to base 2;
+        // 23 is in SMALL_PRIMES, so trial division rejects it before the
+        // Miller-Rabin and Lucas stages run.
+        assert!(!U1024::from_u64(2047).is_probable_prime());
+    }
+
+    #[test]
+    fn test_perfect_square_is_composite() {
+        assert!(!U1024::from_u64(121).is_probable_prime()); // 11^2
+        assert!(!U1024::from_u64(49).is_probable_prime()); // 7^2
+    }
+
+    #[test]
+    fn test_matches_trial_division_below_2000() {
+        for n in 2u64..2_000 {
+            let expected = trial_division_is_prime(n);
+            let actual = U1024::from_u64(n).is_probable_prime();
+            assert_eq!(actual, expected, "mismatch at n={}", n);
+        }
+    }
+
+    #[test]
+    fn test_jacobi_symbol_known_values() {
+        // (5/7): 5 is not a QR mod 7 (QRs mod 7 are 1,2,4) -> -1
+        assert_eq!(jacobi(5, &U1024::from_u64(7)), -1);
+        // (2/7): 2 is a QR mod 7 -> 1
+        assert_eq!(jacobi(2, &U1024::from_u64(7)), 1);
+        // (-1/5): 5 ≡ 1 mod 4 -> 1
+        assert_eq!(jacobi(-1, &U1024::from_u64(5)), 1);
+    }
+}
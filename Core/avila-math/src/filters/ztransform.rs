@@ -77,14 +77,160 @@ pub fn evaluate_at(signal: &[f64], z: Complex64) -> Complex64 {
 
 /// Compute poles and zeros of a transfer function
 ///
-/// For a transfer function H(z) = B(z)/A(z)
-/// * `b` - Numerator coefficients
-/// * `a` - Denominator coefficients
-pub fn poles_zeros(_b: &[f64], _a: &[f64]) -> (Vec<Complex64>, Vec<Complex64>) {
-    // This is simplified - in practice, use eigenvalue solver
-    // For now, return empty vectors
-    // TODO: Implement companion matrix eigenvalue method
-    (vec![], vec![])
+/// For a transfer function H(z) = B(z)/A(z), both `b` and `a` are given in
+/// descending powers of z (`coeffs[0]` is the leading coefficient, matching
+/// `evaluate_at`'s `a[n]` being the coefficient of `z^-n`). The poles are
+/// the roots of `A(z)`, the zeros are the roots of `B(z)`; each is found by
+/// building the polynomial's companion matrix and running a QR iteration
+/// to extract its eigenvalues.
+///
+/// # Returns
+/// `(poles, zeros)`
+pub fn poles_zeros(b: &[f64], a: &[f64]) -> (Vec<Complex64>, Vec<Complex64>) {
+    let poles = if a.len() < 2 {
+        vec![]
+    } else {
+        eigenvalues_qr(companion_matrix(a))
+    };
+    let zeros = if b.len() < 2 {
+        vec![]
+    } else {
+        eigenvalues_qr(companion_matrix(b))
+    };
+    (poles, zeros)
+}
+
+/// Builds the companion matrix of a polynomial given in descending powers
+/// of z. Its eigenvalues are exactly the polynomial's roots.
+fn companion_matrix(coeffs: &[f64]) -> Vec<Vec<Complex64>> {
+    let n = coeffs.len() - 1;
+    let lead = coeffs[0];
+    let mut matrix = vec![vec![Complex64::new(0.0, 0.0); n]; n];
+
+    for k in 0..n {
+        matrix[0][k] = Complex64::new(-coeffs[k + 1] / lead, 0.0);
+    }
+    for i in 1..n {
+        matrix[i][i - 1] = Complex64::new(1.0, 0.0);
+    }
+
+    matrix
+}
+
+/// Wilkinson-shifted QR iteration for a general (not necessarily symmetric)
+/// complex matrix. The matrix converges towards (quasi-)triangular Schur
+/// form, whose diagonal holds the eigenvalues, as the off-diagonal energy
+/// decays each sweep.
+///
+/// A real companion matrix can have complex-conjugate eigenvalue pairs
+/// (any filter with a resonant/bandpass pole does), and an *unshifted*
+/// iteration never finds them: every intermediate matrix stays real, and a
+/// real matrix has no genuinely complex entries to converge to. Subtracting
+/// a shift taken from the trailing 2x2 block's own eigenvalues (which are
+/// complex exactly when the corresponding pole pair is) seeds that
+/// complex component into the iteration and restores convergence.
+fn eigenvalues_qr(mut matrix: Vec<Vec<Complex64>>) -> Vec<Complex64> {
+    let n = matrix.len();
+    if n == 0 {
+        return vec![];
+    }
+    if n == 1 {
+        return matrix.into_iter().map(|row| row[0]).collect();
+    }
+
+    const ITERATIONS: usize = 500;
+    for _ in 0..ITERATIONS {
+        let shift = wilkinson_shift(&matrix);
+        for i in 0..n {
+            matrix[i][i] = matrix[i][i] - shift;
+        }
+        let (q, r) = qr_decompose(&matrix);
+        matrix = matmul(&r, &q);
+        for i in 0..n {
+            matrix[i][i] = matrix[i][i] + shift;
+        }
+    }
+
+    (0..n).map(|i| matrix[i][i]).collect()
+}
+
+/// Picks the eigenvalue of the trailing 2x2 submatrix closest to its
+/// bottom-right entry, the standard Wilkinson shift used to accelerate
+/// (and, for complex-conjugate pairs, enable) QR convergence.
+fn wilkinson_shift(matrix: &[Vec<Complex64>]) -> Complex64 {
+    let n = matrix.len();
+    let a = matrix[n - 2][n - 2];
+    let b = matrix[n - 2][n - 1];
+    let c = matrix[n - 1][n - 2];
+    let d = matrix[n - 1][n - 1];
+
+    let trace = a + d;
+    let det = a * d - b * c;
+    let two = Complex64::new(2.0, 0.0);
+    let four = Complex64::new(4.0, 0.0);
+    let disc = (trace * trace - four * det).sqrt();
+    let l1 = (trace + disc) / two;
+    let l2 = (trace - disc) / two;
+
+    if (l1 - d).norm() <= (l2 - d).norm() {
+        l1
+    } else {
+        l2
+    }
+}
+
+/// Modified Gram-Schmidt QR decomposition of a square complex matrix.
+fn qr_decompose(a: &[Vec<Complex64>]) -> (Vec<Vec<Complex64>>, Vec<Vec<Complex64>>) {
+    let n = a.len();
+    let zero = Complex64::new(0.0, 0.0);
+    let mut q = vec![vec![zero; n]; n];
+    let mut r = vec![vec![zero; n]; n];
+
+    for j in 0..n {
+        let mut v: Vec<Complex64> = (0..n).map(|i| a[i][j]).collect();
+
+        for i in 0..j {
+            let mut dot = zero;
+            for k in 0..n {
+                dot += q[k][i].conj() * v[k];
+            }
+            r[i][j] = dot;
+            for k in 0..n {
+                v[k] = v[k] - dot * q[k][i];
+            }
+        }
+
+        let norm = v.iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+        r[j][j] = Complex64::new(norm, 0.0);
+        if norm > 1e-14 {
+            for k in 0..n {
+                q[k][j] = v[k] / norm;
+            }
+        } else {
+            q[j][j] = Complex64::new(1.0, 0.0);
+        }
+    }
+
+    (q, r)
+}
+
+fn matmul(a: &[Vec<Complex64>], b: &[Vec<Complex64>]) -> Vec<Vec<Complex64>> {
+    let n = a.len();
+    let zero = Complex64::new(0.0, 0.0);
+    let mut out = vec![vec![zero; n]; n];
+
+    for i in 0..n {
+        for k in 0..n {
+            if a[i][k] == zero {
+                continue;
+            }
+            for j in 0..n {
+                out[i][j] += a[i][k] * b[k][j];
+            }
+        }
+    }
+
+    out
 }
 
 /// Compute frequency response H(e^jω) on unit circle
@@ -117,10 +263,56 @@ pub fn frequency_response(b: &[f64], a: &[f64], n_points: usize) -> ZTransform {
 }
 
 /// Check system stability (all poles inside unit circle)
-pub fn is_stable(_a: &[f64]) -> bool {
-    // Simplified check - should compute poles properly
-    // A system is stable if all poles are inside unit circle (|z| < 1)
-    true // Placeholder
+///
+/// A system is stable if all poles are inside the unit circle (`|z| < 1`).
+/// This computes the poles via [`poles_zeros`]'s companion-matrix/QR route;
+/// for a cheaper, allocation-light alternative see [`jury_stable`].
+pub fn is_stable(a: &[f64]) -> bool {
+    if a.len() < 2 {
+        return true;
+    }
+    let poles = eigenvalues_qr(companion_matrix(a));
+    poles.iter().all(|z| z.norm() < 1.0)
+}
+
+/// Jury (Schur-Cohn) stability test, run directly on the denominator
+/// coefficients without ever forming a matrix.
+///
+/// Repeatedly reduces the coefficient array via
+/// `row'[k] = row[k] - (row[last] / row[0]) * row[last - k]`, requiring
+/// each new leading term to stay positive; the filter is stable iff this
+/// never fails before the array shrinks to a single term.
+pub fn jury_stable(a: &[f64]) -> bool {
+    if a.len() < 2 {
+        return !a.is_empty() && a[0] != 0.0;
+    }
+
+    let mut row: Vec<f64> = if a[0] < 0.0 {
+        a.iter().map(|&c| -c).collect()
+    } else {
+        a.to_vec()
+    };
+    if row[0] <= 0.0 {
+        return false;
+    }
+
+    let mut next = Vec::with_capacity(row.len());
+    while row.len() > 1 {
+        let last = row.len() - 1;
+        let ratio = row[last] / row[0];
+
+        next.clear();
+        for k in 0..last {
+            next.push(row[k] - ratio * row[last - k]);
+        }
+
+        if next[0] <= 0.0 {
+            return false;
+        }
+        core::mem::swap(&mut row, &mut next);
+    }
+
+    true
 }
 
 /// Design a simple low-pass filter using bilinear transform
@@ -182,4 +374,51 @@ mod tests {
         assert_eq!(a.len(), 1);
         assert!((a[0] - 1.0).abs() < 1e-6);
     }
+
+    #[test]
+    fn test_poles_zeros_first_order() {
+        // A(z) = z - 0.5, B(z) = z - 0.25
+        let a = vec![1.0, -0.5];
+        let b = vec![1.0, -0.25];
+        let (poles, zeros) = poles_zeros(&b, &a);
+
+        assert_eq!(poles.len(), 1);
+        assert!((poles[0] - Complex64::new(0.5, 0.0)).norm() < 1e-6);
+
+        assert_eq!(zeros.len(), 1);
+        assert!((zeros[0] - Complex64::new(0.25, 0.0)).norm() < 1e-6);
+    }
+
+    #[test]
+    fn test_poles_zeros_complex_conjugate_pair() {
+        // A(z) = z^2 - 0.6z + 0.25 has roots 0.3 +- 0.4i (both |z| = 0.5)
+        let a = vec![1.0, -0.6, 0.25];
+        let (poles, _zeros) = poles_zeros(&[], &a);
+
+        assert_eq!(poles.len(), 2);
+        for pole in &poles {
+            assert!((pole.norm() - 0.5).abs() < 1e-6);
+            assert!(pole.im.abs() > 0.1, "expected a complex pole, got {pole:?}");
+        }
+    }
+
+    #[test]
+    fn test_is_stable() {
+        assert!(is_stable(&[1.0, -0.5])); // pole at 0.5, inside unit circle
+        assert!(!is_stable(&[1.0, -2.0])); // pole at 2.0, outside unit circle
+    }
+
+    #[test]
+    fn test_jury_stable_matches_is_stable() {
+        assert!(jury_stable(&[1.0, -0.5]));
+        assert!(!jury_stable(&[1.0, -2.0]));
+    }
+
+    #[test]
+    fn test_jury_stable_second_order() {
+        // Roots at 0.5 and -0.3: A(z) = (z - 0.5)(z + 0.3) = z^2 - 0.2z - 0.15
+        assert!(jury_stable(&[1.0, -0.2, -0.15]));
+        // Roots at 0.5 and 2.0: A(z) = (z - 0.5)(z - 2.0) = z^2 - 2.5z + 1.0
+        assert!(!jury_stable(&[1.0, -2.5, 1.0]));
+    }
 }
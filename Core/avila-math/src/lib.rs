@@ -65,6 +65,13 @@ pub mod autograd;
 /// Filtro de Kalman, Wiener e Transformada Z para sistemas discretos.
 pub mod filters;
 
+/// Módulo de Ponto Flutuante por Software
+///
+/// `SoftF128`, um binary128 (IEEE-754 quad precision) construído sobre as
+/// primitivas de 64 bits da `avila-nucleus`, para aritmética determinística
+/// nos caminhos de DSP/Transformada Z.
+pub mod softfloat;
+
 /// Bindings Python (PyO3)
 #[cfg(feature = "python")]
 pub mod python;
@@ -72,5 +79,6 @@ pub mod python;
 // Re-export commonly used types
 pub use autograd::{Tape, Variable};
 pub use filters::{KalmanFilter, WienerFilter};
+pub use softfloat::SoftF128;
 pub use geometry::{DualQuat, Matrix4, Quat3D, SO4Rotation, Vector2, Vector3, Vector4, AABB};
 pub use tensor::{Tensor, Tensor4D};
@@ -16,7 +16,15 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+extern crate alloc;
+
+// `define_biguint!` lives here and generates every unsigned width below from
+// the same limb primitives, so it must be declared before the modules that
+// invoke it.
+pub mod biguint;
+
 pub mod u256;
+pub mod u384;
 pub mod u512;
 pub mod u1024;
 pub mod u2048;
@@ -32,6 +40,7 @@ pub mod traits;
 
 // Re-export types at the root for easier access
 pub use u256::U256;
+pub use u384::U384;
 pub use u512::U512;
 pub use u1024::U1024;
 pub use u2048::U2048;
@@ -49,6 +58,7 @@ pub mod prelude {
     //! Common imports for convenience
 
     pub use crate::u256::U256;
+    pub use crate::u384::U384;
     pub use crate::u512::U512;
     pub use crate::u1024::U1024;
     pub use crate::u2048::U2048;
@@ -1,5 +1,8 @@
 //! Common traits for big integer types
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use core::ops::{Add, Sub, Mul, Div, Rem, BitAnd, BitOr, BitXor, Not, Shl, Shr};
 use core::cmp::{PartialOrd, Ord};
 
@@ -33,8 +36,10 @@ pub trait BigUint:
     /// Create from little-endian bytes
     fn from_le_bytes(bytes: &[u8]) -> Self;
 
-    /// Convert to little-endian bytes (fixed size array)
-    fn to_le_bytes(&self) -> &[u8];
+    /// Convert to little-endian bytes. Returns an owned `Vec` rather than a
+    /// borrowed slice so implementers can build the bytes limb-by-limb
+    /// instead of transmuting the type, which needs no `unsafe`.
+    fn to_le_bytes(&self) -> Vec<u8>;
 
     /// Get number of bits
     fn bits(&self) -> u32;
@@ -0,0 +1,37 @@
+//! 384-bit unsigned integer type
+
+crate::define_biguint!(U384, 6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_arithmetic() {
+        let a = U384::from_u64(100);
+        let b = U384::from_u64(50);
+
+        assert_eq!((a + b).to_u64(), 150);
+        assert_eq!((a - b).to_u64(), 50);
+        assert_eq!((a * b).to_u64(), 5000);
+    }
+
+    #[test]
+    fn test_division() {
+        let a = U384::from_u64(107);
+        let b = U384::from_u64(10);
+
+        assert_eq!((a / b).to_u64(), 10);
+        assert_eq!((a % b).to_u64(), 7);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let a = U384::from_u64(100);
+        let b = U384::from_u64(50);
+
+        assert!(a > b);
+        assert!(b < a);
+        assert_eq!(a, a);
+    }
+}
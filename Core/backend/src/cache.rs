@@ -123,15 +123,23 @@ impl CacheManager {
 
     /// Incrementar com expiry
     pub async fn incr_with_ttl(&self, key: &str, ttl: Duration) -> Result<i64> {
+        self.incr_by_with_ttl(key, 1, ttl).await
+    }
+
+    /// Incrementar por um delta arbitrário, com expiry
+    ///
+    /// Usado pelo `DeferredRateLimiter` para reconciliar de uma vez vários
+    /// incrementos acumulados localmente.
+    pub async fn incr_by_with_ttl(&self, key: &str, delta: i64, ttl: Duration) -> Result<i64> {
         let mut conn = self.client.clone();
-        
+
         let value: i64 = conn
-            .incr(key, 1)
+            .incr(key, delta)
             .await
             .map_err(|e| AppError::InternalError(format!("Redis incr error: {}", e)))?;
 
-        // Set TTL apenas se for a primeira vez (value == 1)
-        if value == 1 {
+        // Set TTL apenas se for a primeira vez (valor total igual ao delta aplicado)
+        if value == delta {
             conn.expire(key, ttl.as_secs() as i64)
                 .await
                 .map_err(|e| AppError::InternalError(format!("Redis expire error: {}", e)))?;
@@ -140,6 +148,50 @@ impl CacheManager {
         Ok(value)
     }
 
+    /// Mescla `bytes` (registrador a registrador, por máximo) com o array de
+    /// bytes já armazenado em `key`, sob um único `EVAL` atômico, e aplica
+    /// `ttl` ao resultado.
+    ///
+    /// Usado pelo `DeferredRateLimiter` para combinar o HyperLogLog acumulado
+    /// localmente com o de outras instâncias do backend sem a corrida de um
+    /// GET/mesclar/SET em dois round-trips, onde duas instâncias flushando a
+    /// mesma janela concorrentemente fariam a segunda `set` sobrescrever o
+    /// merge da primeira em vez de incorporá-lo.
+    pub async fn merge_max_bytes_with_ttl(&self, key: &str, bytes: &[u8], ttl: Duration) -> Result<()> {
+        const MERGE_MAX_SCRIPT: &str = r#"
+            local existing = redis.call('GET', KEYS[1])
+            local new = cjson.decode(ARGV[1])
+            local merged
+            if existing then
+                local old = cjson.decode(existing)
+                merged = {}
+                for i = 1, #new do
+                    local o = old[i] or 0
+                    merged[i] = (o > new[i]) and o or new[i]
+                end
+            else
+                merged = new
+            end
+            redis.call('SET', KEYS[1], cjson.encode(merged), 'EX', ARGV[2])
+            return 1
+        "#;
+
+        let mut conn = self.client.clone();
+
+        let new_json = serde_json::to_string(bytes)
+            .map_err(|e| AppError::InternalError(format!("JSON serialize error: {}", e)))?;
+
+        redis::Script::new(MERGE_MAX_SCRIPT)
+            .key(key)
+            .arg(new_json)
+            .arg(ttl.as_secs() as i64)
+            .invoke_async::<_, i64>(&mut conn)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Redis merge script error: {}", e)))?;
+
+        Ok(())
+    }
+
     /// Get ou set (cache-aside pattern)
     pub async fn get_or_set<T, F, Fut>(
         &self,
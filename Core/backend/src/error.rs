@@ -6,6 +6,8 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+use crate::middleware::request_id::current_request_id;
+
 pub type Result<T> = std::result::Result<T, AppError>;
 
 #[derive(Debug, thiserror::Error)]
@@ -73,9 +75,11 @@ pub struct ValidationError {
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let request_id = current_request_id();
+
         let (status, error_code, error_message, details) = match self {
             AppError::Database(ref e) => {
-                tracing::error!("MongoDB error: {:?}", e);
+                tracing::error!(request_id = request_id.as_deref(), "MongoDB error: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "DATABASE_ERROR",
@@ -84,7 +88,7 @@ impl IntoResponse for AppError {
                 )
             }
             AppError::Sqlx(ref e) => {
-                tracing::error!("PostgreSQL error: {:?}", e);
+                tracing::error!(request_id = request_id.as_deref(), "PostgreSQL error: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "DATABASE_ERROR",
@@ -147,7 +151,7 @@ impl IntoResponse for AppError {
                 None,
             ),
             AppError::InternalError(msg) => {
-                tracing::error!("Internal error: {}", msg);
+                tracing::error!(request_id = request_id.as_deref(), "Internal error: {}", msg);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "INTERNAL_ERROR",
@@ -156,7 +160,7 @@ impl IntoResponse for AppError {
                 )
             }
             AppError::Internal(ref e) => {
-                tracing::error!("Internal error: {:?}", e);
+                tracing::error!(request_id = request_id.as_deref(), "Internal error: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "INTERNAL_ERROR",
@@ -171,7 +175,7 @@ impl IntoResponse for AppError {
                 code: error_code.to_string(),
                 message: error_message,
                 details,
-                request_id: None, // TODO: Adicionar request ID do tracing
+                request_id,
             },
         });
 
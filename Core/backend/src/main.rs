@@ -1,7 +1,7 @@
 use anyhow::Context;
 use axum::{
     extract::State,
-    middleware,
+    middleware as axum_middleware,
     response::Json,
     routing::get,
     Router,
@@ -19,6 +19,7 @@ mod auth;
 mod cache;
 mod db;
 mod error;
+mod middleware;
 mod models;
 mod routes;
 mod webhooks;
@@ -106,11 +107,21 @@ async fn main() -> anyhow::Result<()> {
         // .nest("/analytics", routes::analytics::routes(pool.clone()))
 
         // Middleware de autenticação (aplicado a todas as rotas acima)
-        .layer(middleware::from_fn(auth_middleware));
+        .layer(axum_middleware::from_fn(auth_middleware));
+
+    // Rate limit por IP (protege /login contra brute force antes da autenticação)
+    let ip_rate_limiter = Arc::new(middleware::rate_limit::IpRateLimiter::new(
+        cache.clone(),
+        middleware::rate_limit::IpRateLimitConfig::from_env(),
+    ));
 
     // Auth routes (sem autenticação)
     let auth_routes = auth::auth_routes()
-        .with_state(auth_config.clone());
+        .with_state(auth_config.clone())
+        .layer(axum_middleware::from_fn_with_state(
+            ip_rate_limiter,
+            middleware::rate_limit::ip_rate_limit_middleware,
+        ));
 
     // Health & Metrics
     let health_routes = Router::new()
@@ -126,6 +137,7 @@ async fn main() -> anyhow::Result<()> {
         .merge(health_routes)
         .layer(
             ServiceBuilder::new()
+                .layer(axum_middleware::from_fn(middleware::request_id::request_id_middleware))
                 .layer(TraceLayer::new_for_http())
                 .layer(CompressionLayer::new())
                 .layer(TimeoutLayer::new(Duration::from_secs(30)))
@@ -148,7 +160,11 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("📖 API: http://{}/api/v1", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -1,13 +1,17 @@
 // middleware/rate_limit.rs - Rate Limiting por Tenant e Plano
 
 use axum::{
-    extract::{Request, State},
+    extract::{ConnectInfo, Request, State},
     http::{HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
-use std::sync::Arc;
-use std::time::Duration;
+use dashmap::DashMap;
+use ipnet::IpNet;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::{
     auth::Claims,
@@ -68,7 +72,7 @@ impl RateLimitConfig {
 
 /// Middleware de rate limiting
 pub async fn rate_limit_middleware(
-    State(cache): State<Arc<CacheManager>>,
+    State(limiter): State<Arc<DeferredRateLimiter>>,
     request: Request,
     next: Next,
 ) -> Result<Response, AppError> {
@@ -82,14 +86,18 @@ pub async fn rate_limit_middleware(
     let tenant_id = &claims.tenant_id;
 
     // Buscar configuração do tenant (com cache)
-    let config = get_tenant_rate_limit_config(&cache, tenant_id).await?;
+    let config = get_tenant_rate_limit_config(&limiter.cache, tenant_id).await?;
 
-    // Verificar rate limit
-    let is_allowed = check_rate_limit(&cache, tenant_id, &config).await?;
+    // Verificar rate limit (via contagem local com reconciliação periódica no Redis)
+    let is_allowed = limiter.check_and_increment(tenant_id, &config).await?;
+
+    // Alimentar estatísticas do tenant (total, rejeitadas, clientes únicos),
+    // com a mesma contagem local + reconciliação periódica do contador acima
+    limiter.record_stats(tenant_id, &claims.sub, !is_allowed).await?;
 
     if !is_allowed {
         tracing::warn!("Rate limit exceeded for tenant: {}", tenant_id);
-        
+
         return Err(AppError::RateLimitExceeded);
     }
 
@@ -97,25 +105,281 @@ pub async fn rate_limit_middleware(
     let mut response = next.run(request).await;
 
     // Adicionar headers de rate limit na response
-    add_rate_limit_headers(response.headers_mut(), &cache, tenant_id, &config).await;
+    add_rate_limit_headers(response.headers_mut(), &limiter.cache, tenant_id, &config).await;
 
     Ok(response)
 }
 
-/// Verificar se request está dentro do limite
-async fn check_rate_limit(
-    cache: &CacheManager,
-    tenant_id: &str,
-    config: &RateLimitConfig,
-) -> Result<bool, AppError> {
-    let key = format!("ratelimit:{}:count", tenant_id);
-    let ttl = Duration::from_secs(60);
+/// Configuração do rate limiter diferido (contagem local + reconciliação periódica no Redis)
+#[derive(Debug, Clone)]
+pub struct DeferredRateLimiterConfig {
+    /// Intervalo máximo entre reconciliações com o Redis para um tenant "quente"
+    pub flush_interval: Duration,
+    /// Força reconciliação a cada N incrementos locais, mesmo antes do intervalo
+    pub flush_every_n: u64,
+}
+
+impl Default for DeferredRateLimiterConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval: Duration::from_millis(500),
+            flush_every_n: 20,
+        }
+    }
+}
+
+/// Contador local de uma janela de rate limit de um tenant
+struct LocalCounter {
+    /// Última contagem confirmada no Redis (já inclui deltas reconciliados)
+    remote_count: AtomicI64,
+    /// Incrementos locais ainda não enviados ao Redis
+    delta: AtomicI64,
+    /// Instante (ms relativos a `DeferredRateLimiter::epoch`) da última reconciliação
+    last_flush_millis: AtomicU64,
+}
+
+/// Estatísticas de um tenant acumuladas localmente entre duas reconciliações
+///
+/// Espelha `LocalCounter`: os deltas de total/rejeitadas e o HyperLogLog de
+/// clientes únicos ficam só no processo até o próximo flush, em vez de tocar
+/// o Redis (com uma (de)serialização JSON do array de registradores) a cada
+/// requisição.
+struct LocalStats {
+    total_delta: AtomicU64,
+    rejected_delta: AtomicU64,
+    /// HLL da janela de 1 minuto corrente, ainda não mesclado no Redis
+    hll: Mutex<HyperLogLog>,
+    /// Janela (`current_window()`) à qual `hll` pertence
+    window: AtomicU64,
+    last_flush_millis: AtomicU64,
+}
+
+/// Rate limiter com contagem local aproximada e reconciliação periódica no Redis
+///
+/// A primeira requisição de um tenant numa janela consulta `CacheManager` normalmente.
+/// As requisições seguintes são respondidas a partir do contador local (`DashMap`) e o
+/// delta acumulado é enviado ao Redis a cada `flush_every_n` incrementos ou
+/// `flush_interval`, o que ocorrer primeiro — aceitando uma pequena margem de
+/// over-count na cauda da janela em troca de tirar o Redis do caminho quente.
+pub struct DeferredRateLimiter {
+    cache: Arc<CacheManager>,
+    local: DashMap<String, LocalCounter>,
+    stats: DashMap<String, LocalStats>,
+    config: DeferredRateLimiterConfig,
+    epoch: Instant,
+}
+
+impl DeferredRateLimiter {
+    pub fn new(cache: Arc<CacheManager>, config: DeferredRateLimiterConfig) -> Self {
+        Self {
+            cache,
+            local: DashMap::new(),
+            stats: DashMap::new(),
+            config,
+            epoch: Instant::now(),
+        }
+    }
+
+    fn now_millis(&self) -> u64 {
+        self.epoch.elapsed().as_millis() as u64
+    }
+
+    /// Verifica e incrementa o contador de rate limit de um tenant
+    ///
+    /// Entrada "fria" (sem contador local ainda) ou "expirada" (a janela de 60s
+    /// que a key do Redis representa já virou desde a última reconciliação
+    /// local): consulta o Redis diretamente e ressincroniza. Entrada "quente":
+    /// incrementa localmente e só toca o Redis quando a reconciliação é devida.
+    pub async fn check_and_increment(
+        &self,
+        tenant_id: &str,
+        config: &RateLimitConfig,
+    ) -> Result<bool, AppError> {
+        let key = format!("ratelimit:{}:count", tenant_id);
+        let ttl = Duration::from_secs(60);
+
+        let is_stale = match self.local.get(&key) {
+            Some(counter) => {
+                let elapsed = self
+                    .now_millis()
+                    .saturating_sub(counter.last_flush_millis.load(Ordering::Relaxed));
+                elapsed >= ttl.as_millis() as u64
+            }
+            None => true,
+        };
+
+        if is_stale {
+            // Caminho direto: entrada fria, ou um `remote_count` que sobreviveu
+            // à própria key do Redis expirar — nenhum dos dois tem um delta
+            // local em que valha a pena confiar, então ressincroniza direto.
+            let remote = self.cache.incr_with_ttl(&key, ttl).await?;
+            self.local.insert(
+                key.clone(),
+                LocalCounter {
+                    remote_count: AtomicI64::new(remote),
+                    delta: AtomicI64::new(0),
+                    last_flush_millis: AtomicU64::new(self.now_millis()),
+                },
+            );
+            return Ok(remote <= config.burst_size as i64);
+        }
+
+        let (total, should_flush) = {
+            let counter = self
+                .local
+                .get(&key)
+                .expect("entrada presente e não expirada, verificado acima");
+            let delta = counter.delta.fetch_add(1, Ordering::Relaxed) + 1;
+            let total = counter.remote_count.load(Ordering::Relaxed) + delta;
+            let elapsed = self
+                .now_millis()
+                .saturating_sub(counter.last_flush_millis.load(Ordering::Relaxed));
+            let should_flush = delta as u64 % self.config.flush_every_n == 0
+                || elapsed >= self.config.flush_interval.as_millis() as u64;
+            (total, should_flush)
+        };
+
+        if should_flush {
+            self.flush(&key, ttl).await?;
+        }
+
+        Ok(total <= config.burst_size as i64)
+    }
+
+    /// Envia o delta local acumulado ao Redis e atualiza o contador remoto conhecido
+    async fn flush(&self, key: &str, ttl: Duration) -> Result<(), AppError> {
+        let pending = match self.local.get(key) {
+            Some(counter) => counter.delta.swap(0, Ordering::Relaxed),
+            None => return Ok(()),
+        };
+
+        if pending == 0 {
+            return Ok(());
+        }
+
+        let remote = self.cache.incr_by_with_ttl(key, pending, ttl).await?;
+
+        if let Some(counter) = self.local.get(key) {
+            counter.remote_count.store(remote, Ordering::Relaxed);
+            counter
+                .last_flush_millis
+                .store(self.now_millis(), Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    /// Registra uma requisição nas estatísticas do tenant (contagem local com
+    /// reconciliação periódica no Redis, mesmo padrão de `check_and_increment`)
+    ///
+    /// Acumula total/rejeitadas e soma o cliente no HyperLogLog local da janela
+    /// corrente; só toca o Redis quando a reconciliação é devida.
+    pub async fn record_stats(
+        &self,
+        tenant_id: &str,
+        client_id: &str,
+        rejected: bool,
+    ) -> Result<(), AppError> {
+        let window = current_window();
+
+        let (should_flush, previous_hll) = {
+            let entry = self.stats.entry(tenant_id.to_string()).or_insert_with(|| LocalStats {
+                total_delta: AtomicU64::new(0),
+                rejected_delta: AtomicU64::new(0),
+                hll: Mutex::new(HyperLogLog::new()),
+                window: AtomicU64::new(window),
+                last_flush_millis: AtomicU64::new(self.now_millis()),
+            });
+
+            // Nova janela de HLL: o que sobrou da janela anterior ainda não foi
+            // flushado; é separado aqui, mesclado na key dessa janela abaixo, e
+            // o HLL local recomeça do zero.
+            let previous_window = entry.window.swap(window, Ordering::Relaxed);
+            let previous_hll = (previous_window != window).then(|| {
+                let old = std::mem::replace(
+                    &mut *entry.hll.lock().expect("lock do HLL local envenenado"),
+                    HyperLogLog::new(),
+                );
+                (previous_window, old)
+            });
+
+            entry.total_delta.fetch_add(1, Ordering::Relaxed);
+            if rejected {
+                entry.rejected_delta.fetch_add(1, Ordering::Relaxed);
+            }
+            entry
+                .hll
+                .lock()
+                .expect("lock do HLL local envenenado")
+                .add(client_id);
+
+            let elapsed = self
+                .now_millis()
+                .saturating_sub(entry.last_flush_millis.load(Ordering::Relaxed));
+            (elapsed >= self.config.flush_interval.as_millis() as u64, previous_hll)
+        };
+
+        if let Some((previous_window, old)) = previous_hll {
+            let key = hll_key_for_window(tenant_id, previous_window);
+            self.cache
+                .merge_max_bytes_with_ttl(&key, &old.into_registers(), Duration::from_secs(86_400))
+                .await?;
+        }
+
+        if should_flush {
+            self.flush_stats(tenant_id, window).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Envia os deltas de estatísticas acumulados localmente ao Redis
+    ///
+    /// O HLL local é mesclado (registrador a registrador, por máximo) com o
+    /// que já está no Redis via um script atômico (`CacheManager::merge_max_bytes_with_ttl`),
+    /// em vez de um GET/mesclar/SET em dois round-trips — isso evita que duas
+    /// instâncias flushando a mesma janela concorrentemente se pisem, com a
+    /// segunda sobrescrevendo o merge da primeira em vez de incorporá-lo.
+    async fn flush_stats(&self, tenant_id: &str, window: u64) -> Result<(), AppError> {
+        let (total_delta, rejected_delta, local_hll) = match self.stats.get(tenant_id) {
+            Some(entry) => {
+                let total_delta = entry.total_delta.swap(0, Ordering::Relaxed);
+                let rejected_delta = entry.rejected_delta.swap(0, Ordering::Relaxed);
+                let local_hll = std::mem::replace(
+                    &mut *entry.hll.lock().expect("lock do HLL local envenenado"),
+                    HyperLogLog::new(),
+                );
+                (total_delta, rejected_delta, local_hll)
+            }
+            None => return Ok(()),
+        };
+
+        let ttl = Duration::from_secs(86_400);
+
+        if total_delta > 0 {
+            self.cache
+                .incr_by_with_ttl(&stats_total_key(tenant_id), total_delta as i64, ttl)
+                .await?;
+        }
+        if rejected_delta > 0 {
+            self.cache
+                .incr_by_with_ttl(&stats_rejected_key(tenant_id), rejected_delta as i64, ttl)
+                .await?;
+        }
+
+        let key = hll_key_for_window(tenant_id, window);
+        self.cache
+            .merge_max_bytes_with_ttl(&key, &local_hll.into_registers(), ttl)
+            .await?;
 
-    // Incrementar contador
-    let count = cache.incr_with_ttl(&key, ttl).await?;
+        if let Some(entry) = self.stats.get(tenant_id) {
+            entry
+                .last_flush_millis
+                .store(self.now_millis(), Ordering::Relaxed);
+        }
 
-    // Verificar se excedeu
-    Ok(count <= config.burst_size as i64)
+        Ok(())
+    }
 }
 
 /// Buscar configuração de rate limit do tenant
@@ -182,41 +446,160 @@ pub fn strict_rate_limit() -> impl Fn(Request, Next) -> std::pin::Pin<Box<dyn st
     }
 }
 
+/// Configuração do rate limit por IP (pré-autenticação)
+#[derive(Debug, Clone)]
+pub struct IpRateLimitConfig {
+    /// CIDRs de proxies confiáveis; só esses podem repassar `X-Forwarded-For`/`X-Real-IP`
+    pub trusted_proxies: Vec<IpNet>,
+    /// CIDRs que nunca são limitados (ex.: rede interna, health checks)
+    pub allowlist: Vec<IpNet>,
+    /// CIDRs sempre bloqueados, independente do limite
+    pub denylist: Vec<IpNet>,
+    pub requests_per_minute: u32,
+}
+
+impl Default for IpRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            trusted_proxies: Vec::new(),
+            allowlist: Vec::new(),
+            denylist: Vec::new(),
+            requests_per_minute: 60,
+        }
+    }
+}
+
+impl IpRateLimitConfig {
+    /// Carrega a configuração das variáveis de ambiente, caindo para
+    /// `Default` quando uma variável não está definida.
+    ///
+    /// `IP_RATE_LIMIT_TRUSTED_PROXIES` / `_ALLOWLIST` / `_DENYLIST`: lista de
+    /// CIDRs separados por vírgula (ex.: `"10.0.0.0/8,172.16.0.0/12"`).
+    /// `IP_RATE_LIMIT_REQUESTS_PER_MINUTE`: inteiro, default 60.
+    ///
+    /// Sem isso, `trusted_proxies` fica sempre vazio atrás de um load
+    /// balancer/reverse proxy real, e `extract_client_ip` nunca confia no
+    /// `X-Forwarded-For`/`X-Real-IP` — o middleware acaba limitando o IP do
+    /// proxy, não o do cliente.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            trusted_proxies: parse_cidr_list_env("IP_RATE_LIMIT_TRUSTED_PROXIES"),
+            allowlist: parse_cidr_list_env("IP_RATE_LIMIT_ALLOWLIST"),
+            denylist: parse_cidr_list_env("IP_RATE_LIMIT_DENYLIST"),
+            requests_per_minute: std::env::var("IP_RATE_LIMIT_REQUESTS_PER_MINUTE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.requests_per_minute),
+        }
+    }
+}
+
+/// Lê uma lista de CIDRs separados por vírgula de uma variável de ambiente,
+/// ignorando entradas em branco e logando (sem falhar) as inválidas.
+fn parse_cidr_list_env(var: &str) -> Vec<IpNet> {
+    std::env::var(var)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| match s.parse::<IpNet>() {
+            Ok(net) => Some(net),
+            Err(e) => {
+                tracing::warn!("CIDR inválido em {}: {:?} ({})", var, s, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Estado do middleware de rate limit por IP
+pub struct IpRateLimiter {
+    cache: Arc<CacheManager>,
+    config: IpRateLimitConfig,
+}
+
+impl IpRateLimiter {
+    pub fn new(cache: Arc<CacheManager>, config: IpRateLimitConfig) -> Self {
+        Self { cache, config }
+    }
+}
+
 /// Rate limiting por IP (antes da autenticação)
+///
+/// Protege endpoints anônimos (ex.: `/login`) de brute force. Diferente de
+/// `rate_limit_middleware`, não depende de `Claims`, então roda antes do
+/// `auth_middleware`.
 pub async fn ip_rate_limit_middleware(
+    State(limiter): State<Arc<IpRateLimiter>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     request: Request,
     next: Next,
 ) -> Result<Response, AppError> {
-    // Extrair IP do request
-    let ip = extract_client_ip(&request);
+    let ip = extract_client_ip(&request, peer.ip(), &limiter.config.trusted_proxies);
 
-    // TODO: Implementar rate limit por IP
-    // Útil para prevenir brute force em /login
-    
-    tracing::debug!("Request from IP: {}", ip);
+    if limiter.config.denylist.iter().any(|net| net.contains(&ip)) {
+        tracing::warn!("Blocked request from denylisted IP: {}", ip);
+        return Err(AppError::Forbidden("IP address is blocked".to_string()));
+    }
+
+    if limiter.config.allowlist.iter().any(|net| net.contains(&ip)) {
+        return Ok(next.run(request).await);
+    }
+
+    let key = format!("ratelimit:ip:{}", ip);
+    let count = limiter
+        .cache
+        .incr_with_ttl(&key, Duration::from_secs(60))
+        .await?;
+
+    if count > limiter.config.requests_per_minute as i64 {
+        tracing::warn!("Rate limit exceeded for IP: {}", ip);
+        return Err(AppError::RateLimitExceeded);
+    }
 
     Ok(next.run(request).await)
 }
 
-/// Extrair IP do cliente
-fn extract_client_ip(request: &Request) -> String {
-    // Tentar headers de proxy
+/// Extrair o IP real do cliente
+///
+/// Só confia em `X-Forwarded-For`/`X-Real-IP` quando o peer direto é um proxy
+/// confiável; caso contrário o header pode ser forjado por qualquer chamador.
+/// Quando confiável, percorre a cadeia de `X-Forwarded-For` da direita para a
+/// esquerda até o primeiro hop que não seja, ele mesmo, um proxy confiável.
+fn extract_client_ip(request: &Request, peer_ip: IpAddr, trusted_proxies: &[IpNet]) -> IpAddr {
+    if !trusted_proxies.iter().any(|net| net.contains(&peer_ip)) {
+        return peer_ip;
+    }
+
     if let Some(forwarded) = request.headers().get("X-Forwarded-For") {
         if let Ok(value) = forwarded.to_str() {
-            if let Some(ip) = value.split(',').next() {
-                return ip.trim().to_string();
+            let hops: Vec<&str> = value.split(',').map(str::trim).collect();
+
+            for hop in hops.iter().rev() {
+                if let Ok(hop_ip) = hop.parse::<IpAddr>() {
+                    if !trusted_proxies.iter().any(|net| net.contains(&hop_ip)) {
+                        return hop_ip;
+                    }
+                }
+            }
+
+            // Toda a cadeia é de proxies confiáveis: usar o hop mais antigo (o cliente original)
+            if let Some(Ok(hop_ip)) = hops.first().map(|hop| hop.parse::<IpAddr>()) {
+                return hop_ip;
             }
         }
     }
 
     if let Some(real_ip) = request.headers().get("X-Real-IP") {
         if let Ok(value) = real_ip.to_str() {
-            return value.to_string();
+            if let Ok(real_ip) = value.trim().parse::<IpAddr>() {
+                return real_ip;
+            }
         }
     }
 
-    // Fallback para conexão direta
-    "unknown".to_string()
+    peer_ip
 }
 
 /// Rate limit para autenticação (prevenir brute force)
@@ -280,23 +663,152 @@ pub struct RateLimitStats {
     pub total_requests: u64,
     pub rejected_requests: u64,
     pub rejection_rate: f64,
+    /// Contagem aproximada de clientes únicos na janela atual (via HyperLogLog)
+    pub unique_clients: u64,
 }
 
 impl RateLimitStats {
     /// Buscar estatísticas de rate limit de um tenant
-    pub async fn for_tenant(
-        cache: &CacheManager,
-        tenant_id: &str,
-    ) -> Result<Self, AppError> {
-        // TODO: Implementar métricas reais
+    pub async fn for_tenant(cache: &CacheManager, tenant_id: &str) -> Result<Self, AppError> {
+        let total_requests = cache
+            .get::<u64>(&stats_total_key(tenant_id))
+            .await?
+            .unwrap_or(0);
+        let rejected_requests = cache
+            .get::<u64>(&stats_rejected_key(tenant_id))
+            .await?
+            .unwrap_or(0);
+
+        let rejection_rate = if total_requests > 0 {
+            rejected_requests as f64 / total_requests as f64
+        } else {
+            0.0
+        };
+
+        let unique_clients = match cache.get::<Vec<u8>>(&hll_key(tenant_id)).await? {
+            Some(registers) => HyperLogLog::from_registers(registers).estimate(),
+            None => 0,
+        };
+
         Ok(Self {
-            total_requests: 0,
-            rejected_requests: 0,
-            rejection_rate: 0.0,
+            total_requests,
+            rejected_requests,
+            rejection_rate,
+            unique_clients,
         })
     }
 }
 
+fn stats_total_key(tenant_id: &str) -> String {
+    format!("ratelimit:{}:stats:total", tenant_id)
+}
+
+fn stats_rejected_key(tenant_id: &str) -> String {
+    format!("ratelimit:{}:stats:rejected", tenant_id)
+}
+
+/// Key do HyperLogLog de clientes únicos, particionado por janela de 1 minuto
+fn hll_key(tenant_id: &str) -> String {
+    hll_key_for_window(tenant_id, current_window())
+}
+
+/// Mesma key que `hll_key`, mas para uma janela explícita (usada ao flushar
+/// estatísticas acumuladas localmente, onde a janela foi capturada antes do
+/// flush em vez de recalculada na hora de montar a key)
+fn hll_key_for_window(tenant_id: &str, window: u64) -> String {
+    format!("ratelimit:{}:hll:{}", tenant_id, window)
+}
+
+/// Janela de 1 minuto (segundos desde epoch / 60), igual à granularidade do rate limit
+fn current_window() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / 60
+}
+
+/// HyperLogLog aproximado para contagem de clientes únicos
+///
+/// Usa `p = 14` (2^14 = 16384 registradores), o que dá um erro padrão de
+/// aproximadamente `1.04 / sqrt(m) ≈ 0.8%`. Cada cliente é hasheado para 64 bits;
+/// os `p` bits mais significativos escolhem o registrador e o registrador guarda
+/// a posição do primeiro bit 1 (contado a partir do bit seguinte) nos bits restantes.
+struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    const PRECISION: u32 = 14;
+    const NUM_REGISTERS: usize = 1 << Self::PRECISION;
+
+    fn new() -> Self {
+        Self {
+            registers: vec![0u8; Self::NUM_REGISTERS],
+        }
+    }
+
+    /// Reconstituir a partir dos registradores serializados no cache
+    fn from_registers(mut registers: Vec<u8>) -> Self {
+        registers.resize(Self::NUM_REGISTERS, 0);
+        Self { registers }
+    }
+
+    fn into_registers(self) -> Vec<u8> {
+        self.registers
+    }
+
+    fn add(&mut self, client: &str) {
+        let hash = Self::hash64(client);
+        let index = (hash >> (64 - Self::PRECISION)) as usize;
+
+        // Bits restantes após os `p` bits do índice; força um bit 1 extra logo
+        // abaixo deles para garantir que `leading_zeros` nunca ultrapasse os 64 - p bits úteis.
+        let rest = (hash << Self::PRECISION) | (1 << (Self::PRECISION - 1));
+        let rank = (rest.leading_zeros() + 1) as u8;
+
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Estimar a cardinalidade pela fórmula de média harmônica do HLL clássico,
+    /// com a correção de small-range (linear counting) para contagens baixas.
+    fn estimate(&self) -> u64 {
+        let m = Self::NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+
+        let sum_inv: f64 = self
+            .registers
+            .iter()
+            .map(|&r| 2f64.powi(-(r as i32)))
+            .sum();
+        let raw_estimate = alpha * m * m / sum_inv;
+
+        let estimate = if raw_estimate <= 2.5 * m {
+            let zero_registers = self.registers.iter().filter(|&&r| r == 0).count();
+            if zero_registers > 0 {
+                m * (m / zero_registers as f64).ln()
+            } else {
+                raw_estimate
+            }
+        } else {
+            raw_estimate
+        };
+
+        estimate.round().max(0.0) as u64
+    }
+
+    fn hash64(s: &str) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,4 +837,60 @@ mod tests {
         let config = RateLimitConfig::from_plan("unknown");
         assert_eq!(config.requests_per_minute, 100);
     }
+
+    #[test]
+    fn test_hyperloglog_estimate_within_error_margin() {
+        let mut hll = HyperLogLog::new();
+
+        for i in 0..10_000 {
+            hll.add(&format!("client-{}", i));
+        }
+
+        let estimate = hll.estimate();
+        let error = (estimate as f64 - 10_000.0).abs() / 10_000.0;
+
+        assert!(error < 0.05, "estimate {} too far off 10000", estimate);
+    }
+
+    #[test]
+    fn test_hyperloglog_roundtrips_through_registers() {
+        let mut hll = HyperLogLog::new();
+        hll.add("client-a");
+        hll.add("client-b");
+        let original_estimate = hll.estimate();
+
+        let restored = HyperLogLog::from_registers(hll.into_registers());
+
+        assert_eq!(restored.estimate(), original_estimate);
+    }
+
+    fn request_with_forwarded_for(value: &str) -> Request {
+        axum::extract::Request::builder()
+            .header("X-Forwarded-For", value)
+            .body(axum::body::Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_extract_client_ip_untrusted_peer_ignores_forwarded_header() {
+        let request = request_with_forwarded_for("1.2.3.4");
+        let peer: IpAddr = "203.0.113.9".parse().unwrap();
+
+        // Peer não está em nenhum CIDR confiável, então o header é ignorado
+        let ip = extract_client_ip(&request, peer, &[]);
+
+        assert_eq!(ip, peer);
+    }
+
+    #[test]
+    fn test_extract_client_ip_trusted_proxy_walks_chain_to_first_untrusted_hop() {
+        let trusted: IpNet = "10.0.0.0/8".parse().unwrap();
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        // Da esquerda para a direita: cliente real, depois dois proxies confiáveis
+        let request = request_with_forwarded_for("198.51.100.7, 10.0.0.5, 10.0.0.1");
+
+        let ip = extract_client_ip(&request, peer, &[trusted]);
+
+        assert_eq!(ip, "198.51.100.7".parse::<IpAddr>().unwrap());
+    }
 }
@@ -0,0 +1,62 @@
+// middleware/request_id.rs - Correlação de requests via X-Request-Id
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use ulid::Ulid;
+
+/// Header usado para receber/ecoar o request id
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Request id associado ao request atual (disponível via extensions)
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+/// Middleware que garante um request id por requisição
+///
+/// Usa o `X-Request-Id` recebido do cliente/proxy quando presente e não vazio,
+/// ou gera um novo ULID caso contrário. O id é guardado nas extensions do
+/// request, anexado ao span de tracing da requisição e ecoado de volta no
+/// header `X-Request-Id` da response.
+pub async fn request_id_middleware(mut request: Request, next: Next) -> Response {
+    let header_name = HeaderName::from_static(REQUEST_ID_HEADER);
+
+    let request_id = request
+        .headers()
+        .get(&header_name)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| Ulid::new().to_string());
+
+    request
+        .extensions_mut()
+        .insert(RequestId(request_id.clone()));
+
+    let span = tracing::info_span!("request", request_id = %request_id);
+
+    let mut response = REQUEST_ID
+        .scope(request_id.clone(), next.run(request))
+        .instrument(span)
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(header_name, value);
+    }
+
+    response
+}
+
+/// Request id do request em processamento, se chamado de dentro do escopo de
+/// `request_id_middleware` (ex.: em `IntoResponse for AppError`)
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
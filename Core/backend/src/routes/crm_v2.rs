@@ -3,7 +3,7 @@ use crate::{
     cache::{CacheInvalidation, CacheKeys, CacheManager, CacheTTL},
     db::DbPool,
     error::{AppError, Result},
-    middleware::rate_limit::rate_limit_middleware,
+    middleware::rate_limit::{rate_limit_middleware, DeferredRateLimiter, DeferredRateLimiterConfig},
     models::*,
 };
 use axum::{
@@ -31,6 +31,10 @@ pub fn routes(pool: DbPool, cache: Arc<CacheManager>) -> Router {
         pool: pool.clone(),
         cache: cache.clone(),
     });
+    let limiter = Arc::new(DeferredRateLimiter::new(
+        cache,
+        DeferredRateLimiterConfig::default(),
+    ));
 
     Router::new()
         .route("/leads", post(create_lead).get(list_leads))
@@ -50,7 +54,7 @@ pub fn routes(pool: DbPool, cache: Arc<CacheManager>) -> Router {
         .route("/contacts", post(create_contact).get(list_contacts))
         .route("/contacts/:id", get(get_contact).patch(update_contact))
         .with_state(state)
-        .layer(from_fn_with_state(cache, rate_limit_middleware))
+        .layer(from_fn_with_state(limiter, rate_limit_middleware))
 }
 
 async fn create_lead(